@@ -104,43 +104,6 @@ impl<R: Row + GetRowData<Name>> SerializableColumn<R> for NameColumn {
     }
 }
 
-struct CsvExporter {
-    output: String,
-}
-
-impl CsvExporter {
-    fn finish(&mut self) {
-        self.output.push('\n');
-    }
-}
-
-impl Exporter for CsvExporter {
-    type Error = serde_json::Error;
-
-    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
-        if col != 0 {
-            self.output.push(',');
-        }
-        self.output.push_str(&serde_json::to_string(header)?);
-        Ok(())
-    }
-
-    fn serialize_cell<'a>(
-        &mut self,
-        _row: usize,
-        col: usize,
-        cell: impl Serialize + 'a,
-    ) -> Result<(), Self::Error> {
-        if col == 0 {
-            self.output.push('\n');
-        } else {
-            self.output.push(',');
-        }
-        self.output.push_str(&serde_json::to_string(&cell)?);
-        Ok(())
-    }
-}
-
 #[component]
 pub fn Table<R: Row, C: Columns<R> + SerializableColumns<R>>(
     rows: ReadSignal<Vec<R>>,
@@ -165,12 +128,10 @@ pub fn Table<R: Row, C: Columns<R> + SerializableColumns<R>>(
         }
         button {
             onclick: move |_| {
-                let mut exporter = CsvExporter {
-                    output: String::new(),
-                };
+                let mut exporter = CsvExporter::new(Vec::new());
                 data.serialize(&mut exporter).unwrap();
-                exporter.finish();
-                serialized.set(exporter.output);
+                let csv = exporter.into_inner();
+                serialized.set(String::from_utf8(csv).unwrap());
             },
             "serialize"
         }
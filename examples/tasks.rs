@@ -154,7 +154,7 @@ impl<R: Row + GetRowData<Title>> TableColumn<R> for TitleColumn {
         let mut filter_signal = self.filter;
 
         rsx! {
-            th {..attributes,
+            th { position: "relative", ..attributes,
                 SortButton { context, label: "Title".to_string() }
                 input {
                     r#type: "text",
@@ -169,6 +169,10 @@ impl<R: Row + GetRowData<Title>> TableColumn<R> for TitleColumn {
                         }
                     },
                 }
+                ColumnResizeHandle {
+                    context,
+                    style: "position: absolute; top: 0; right: 0; bottom: 0; width: 5px; cursor: col-resize;",
+                }
             }
         }
     }
@@ -190,9 +194,21 @@ impl<R: Row + GetRowData<Title>> TableColumn<R> for TitleColumn {
         }
     }
 
+    fn search_text(&self, row: &R) -> Option<String> {
+        Some(row.get().0)
+    }
+
     fn compare(&self, a: &R, b: &R) -> Ordering {
         a.get().0.cmp(&b.get().0)
     }
+
+    fn default_width(&self) -> Option<f64> {
+        Some(240.0)
+    }
+
+    fn min_width(&self) -> Option<f64> {
+        Some(120.0)
+    }
 }
 
 impl<R: Row + GetRowData<Title>> SerializableColumn<R> for TitleColumn {
@@ -338,6 +354,14 @@ impl<R: Row + GetRowData<TaskStatus>> TableColumn<R> for StatusColumn {
     fn compare(&self, a: &R, b: &R) -> Ordering {
         a.get().0.cmp(&b.get().0)
     }
+
+    fn search_text(&self, row: &R) -> Option<String> {
+        Some(row.get().0.as_str().to_string())
+    }
+
+    fn group_key(&self, row: &R) -> Option<String> {
+        Some(row.get().0.as_str().to_string())
+    }
 }
 
 impl<R: Row + GetRowData<TaskStatus>> SerializableColumn<R> for StatusColumn {
@@ -428,51 +452,6 @@ impl<R: Row + GetRowData<DaysUntil>> SerializableColumn<R> for DaysColumn {
     }
 }
 
-// ==================== CSV Exporter ====================
-
-struct CsvExporter {
-    output: String,
-}
-
-impl CsvExporter {
-    fn new() -> Self {
-        Self {
-            output: String::new(),
-        }
-    }
-
-    fn finish(self) -> String {
-        self.output
-    }
-}
-
-impl Exporter for CsvExporter {
-    type Error = serde_json::Error;
-
-    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
-        if col != 0 {
-            self.output.push(',');
-        }
-        self.output.push_str(&serde_json::to_string(header)?);
-        Ok(())
-    }
-
-    fn serialize_cell<'a>(
-        &mut self,
-        _row: usize,
-        col: usize,
-        cell: impl serde::Serialize + 'a,
-    ) -> Result<(), Self::Error> {
-        if col == 0 {
-            self.output.push('\n');
-        } else {
-            self.output.push(',');
-        }
-        self.output.push_str(&serde_json::to_string(&cell)?);
-        Ok(())
-    }
-}
-
 // ==================== Sort Button Component ====================
 
 #[component]
@@ -627,10 +606,10 @@ fn app() -> Element {
 
     // Export handler
     let export_csv = move |_| {
-        let mut exporter = CsvExporter::new();
+        let mut exporter = CsvExporter::new(Vec::new());
         if let Ok(()) = data.serialize(&mut exporter) {
-            let csv = exporter.finish();
-            println!("CSV Export:\n{}", csv);
+            let csv = exporter.into_inner();
+            println!("CSV Export:\n{}", String::from_utf8_lossy(&csv));
             // In a real app, you would download this or copy to clipboard
         }
     };
@@ -641,6 +620,15 @@ fn app() -> Element {
 
             // Controls
             div { style: "margin: 20px 0; display: flex; gap: 20px; align-items: center; flex-wrap: wrap;",
+                // Table-wide Search
+                input {
+                    r#type: "search",
+                    placeholder: "Search all columns...",
+                    style: "padding: 6px; min-width: 200px;",
+                    value: data.search_query(),
+                    oninput: move |e| data.set_search_query(e.value()),
+                }
+
                 // Column Visibility Toggles
                 div { style: "display: flex; gap: 10px; align-items: center;",
                     span { style: "font-weight: bold;", "Columns:" }
@@ -669,6 +657,19 @@ fn app() -> Element {
                     }
                 }
 
+                // Grouping Toggle
+                label { style: "display: flex; align-items: center; gap: 5px; cursor: pointer;",
+                    input {
+                        r#type: "checkbox",
+                        checked: !data.group_by().is_empty(),
+                        onchange: move |e| {
+                            // The status column is at index 2
+                            data.set_group_by(if e.checked() { vec![2] } else { vec![] });
+                        },
+                    }
+                    "Group by status"
+                }
+
                 // Export Button
                 button {
                     style: "padding: 8px 16px; background: #3b82f6; color: white; border: none; border-radius: 4px; cursor: pointer; font-weight: bold;",
@@ -686,11 +687,34 @@ fn app() -> Element {
                         }
                     }
                     tbody {
-                        for row in data.rows() {
-                            tr {
-                                key: "{row.key()}",
-                                style: "border-bottom: 1px solid #e5e7eb;",
-                                TableCells { row }
+                        for item in data.grouped_rows() {
+                            match item {
+                                TableItem::GroupHeader(header) => {
+                                    let key = header.key();
+                                    let colspan = header.visible_column_count();
+                                    let arrow = if header.is_collapsed() { "▶" } else { "▼" };
+                                    let label = header.value().unwrap_or_default().to_string();
+                                    let count = header.count();
+                                    rsx! {
+                                        tr {
+                                            key: "{key}",
+                                            style: "background: #f3f4f6; cursor: pointer;",
+                                            onclick: move |_| header.toggle_collapsed(),
+                                            td {
+                                                colspan: "{colspan}",
+                                                style: "padding: 6px; font-weight: bold;",
+                                                "{arrow} {label} ({count})"
+                                            }
+                                        }
+                                    }
+                                }
+                                TableItem::Row(row) => rsx! {
+                                    tr {
+                                        key: "{row.key()}",
+                                        style: "border-bottom: 1px solid #e5e7eb;",
+                                        TableCells { row }
+                                    }
+                                },
                             }
                         }
                     }
@@ -5,9 +5,11 @@ use dioxus::prelude::*;
 ///
 /// This is the main trait you implement to define a column's behavior. Each column
 /// is a self-contained unit that can:
-/// - Render its header and cells
+/// - Render its header, cells and footer
 /// - Filter rows based on custom logic
+/// - Take part in the table-wide search
 /// - Sort rows with custom comparison
+/// - Group rows by a key
 /// - Hold its own reactive state via `Signal`
 ///
 /// # Type Parameter
@@ -104,6 +106,50 @@ use dioxus::prelude::*;
 ///     }
 /// }
 /// ```
+///
+/// # Footer Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Product { price: u32, id: u32 }
+/// # impl Row for Product {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Price(u32);
+/// # impl GetRowData<Price> for Product {
+/// #     fn get(&self) -> Price { Price(self.price) }
+/// # }
+/// #[derive(Clone, PartialEq)]
+/// struct PriceColumn;
+///
+/// impl<R: Row + GetRowData<Price>> TableColumn<R> for PriceColumn {
+///     fn column_name(&self) -> String {
+///         "price".into()
+///     }
+///
+///     fn render_header(&self, _context: ColumnContext, attributes: Vec<Attribute>) -> Element {
+///         rsx! { th { ..attributes, "Price" } }
+///     }
+///
+///     fn render_cell(&self, _context: ColumnContext, row: &R, attributes: Vec<Attribute>) -> Element {
+///         rsx! { td { ..attributes, "¥{row.get().0}" } }
+///     }
+///
+///     // Show the total price of the filtered rows
+///     fn render_footer(
+///         &self,
+///         _context: ColumnContext,
+///         rows: &mut dyn Iterator<Item = &R>,
+///         attributes: Vec<Attribute>,
+///     ) -> Element {
+///         let total: u32 = rows.map(|row| row.get().0).sum();
+///         rsx! { td { ..attributes, "Total: ¥{total}" } }
+///     }
+/// }
+/// ```
 pub trait TableColumn<R: Row>: Clone + PartialEq + 'static {
     /// Returns the unique name of this column.
     ///
@@ -122,16 +168,54 @@ pub trait TableColumn<R: Row>: Clone + PartialEq + 'static {
     /// Typically returns a `<td>` element.
     fn render_cell(&self, context: ColumnContext, row: &R, attributes: Vec<Attribute>) -> Element;
 
+    /// Renders the column footer, typically an aggregate such as a total or an average.
+    ///
+    /// `rows` iterates over all filtered rows in sorted order, ignoring pagination.
+    /// Spread `attributes` onto your footer element.
+    /// Default: an empty `<td>`, which keeps the footer row aligned with the other columns.
+    fn render_footer(
+        &self,
+        context: ColumnContext,
+        rows: &mut dyn Iterator<Item = &R>,
+        attributes: Vec<Attribute>,
+    ) -> Element {
+        let _ = (context, rows);
+        rsx! {
+            td { ..attributes }
+        }
+    }
+
     /// Determines whether a row should be displayed.
     ///
     /// Return `true` to include the row, `false` to filter it out.
-    /// All column filters are combined with AND logic.
+    /// All column filters are combined with AND logic, unless the table has a
+    /// [`FilterExpr`](crate::FilterExpr) combining them differently.
     /// Default: includes all rows.
     fn filter(&self, row: &R) -> bool {
         let _ = row;
         true
     }
 
+    /// Returns whether [`filter`](Self::filter) currently excludes any rows.
+    ///
+    /// A [`FilterExpr`](crate::FilterExpr) leaves inactive filters out, so an `Or` or a `Not`
+    /// over a column without a filter value doesn't match or remove every row.
+    /// Return `false` while the filter lets every row through, e.g. when no filter value is set.
+    /// Default: `true`.
+    fn is_filter_active(&self) -> bool {
+        true
+    }
+
+    /// Returns the text of this row searched by the table-wide search.
+    ///
+    /// A row passes the search if any visible column's text contains the query.
+    /// The search is combined with the column filters using AND logic.
+    /// Default: returns `None`, which excludes this column from the search.
+    fn search_text(&self, row: &R) -> Option<String> {
+        let _ = row;
+        None
+    }
+
     /// Compares two rows for sorting.
     ///
     /// Return `Ordering::Less` if `a < b`, `Ordering::Greater` if `a > b`, or `Ordering::Equal`.
@@ -141,4 +225,37 @@ pub trait TableColumn<R: Row>: Clone + PartialEq + 'static {
         let _ = (a, b);
         std::cmp::Ordering::Equal
     }
+
+    /// Returns the key of the group this row belongs to when the table is grouped by this column.
+    ///
+    /// Rows with equal keys are rendered together under one group header.
+    /// Default: returns `None`, which puts every row in a single group without a key.
+    fn group_key(&self, row: &R) -> Option<String> {
+        let _ = row;
+        None
+    }
+
+    /// Returns the initial width of this column in pixels.
+    ///
+    /// The width is set on headers, cells and footers, can be changed with
+    /// [`ColumnContext::set_width`], and is used to compute the offsets of
+    /// [pinned](ColumnContext::pin_left) columns.
+    /// Default: returns `None`, which leaves the width to the browser. Pinned columns stacked
+    /// after a column without a width can't be offset, so they only get the `data-pinned`
+    /// attribute and don't stick.
+    fn default_width(&self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the smallest width in pixels this column can be resized to.
+    /// Default: returns `None`, allowing any width down to zero.
+    fn min_width(&self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the largest width in pixels this column can be resized to.
+    /// Default: returns `None`, allowing any width.
+    fn max_width(&self) -> Option<f64> {
+        None
+    }
 }
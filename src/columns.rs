@@ -1,12 +1,17 @@
 #[cfg(feature = "export")]
 use crate::Exporter;
-use crate::{Row, TableColumn, TableContext};
+use crate::{ColumnContext, Row, TableColumn};
 use dioxus::prelude::*;
 
 /// Trait automatically implemented for tuples of [`TableColumn`]s.
 ///
 /// This trait is implemented for tuples of 1 to 12 columns via macros.
-/// You don't need to implement this trait manually.
+/// You don't need to implement this trait manually. Tuples of 13 to 32 columns, and tuples of
+/// reusable column sets, don't implement it: pass them to [`use_tabular`](crate::use_tabular),
+/// or convert them with [`IntoColumns::into_columns`] for APIs taking `Columns` like
+/// [`TableContext::use_table_context`](crate::TableContext::use_table_context). Flat tuples
+/// stop at 32 columns, so wider tables must be split into column sets. For columns only known at runtime, it is also
+/// implemented for a `Vec` of boxed [`DynTableColumn`](crate::DynTableColumn)s.
 ///
 /// # Example
 ///
@@ -42,13 +47,30 @@ pub trait Columns<R: Row>: Clone + PartialEq + 'static {
     /// Returns the names of all columns.
     fn column_names(&self) -> Vec<String>;
     /// Returns header renderers for all columns.
-    fn headers(&self) -> Vec<Box<dyn Fn(&TableContext<Self>, Vec<Attribute>) -> Element + '_>>;
+    fn headers(&self) -> Vec<Box<dyn Fn(ColumnContext, Vec<Attribute>) -> Element + '_>>;
+    /// Returns footer renderers for all columns.
+    fn footers(
+        &self,
+    ) -> Vec<Box<dyn Fn(ColumnContext, &mut dyn Iterator<Item = &R>, Vec<Attribute>) -> Element + '_>>;
     /// Returns cell renderers for all columns.
-    fn columns(&self) -> Vec<Box<dyn Fn(&TableContext<Self>, &R, Vec<Attribute>) -> Element + '_>>;
+    fn columns(&self) -> Vec<Box<dyn Fn(ColumnContext, &R, Vec<Attribute>) -> Element + '_>>;
     /// Returns true if the row passes all column filters.
     fn filter(&self, row: &R) -> bool;
+    /// Returns the filters of all columns, to be combined by a [`FilterExpr`](crate::FilterExpr).
+    /// Columns whose filter is not active have `None`.
+    fn filters(&self) -> Vec<Option<Box<dyn Fn(&R) -> bool + '_>>>;
+    /// Returns search text extractors for all columns.
+    fn search_text(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>>;
     /// Returns comparators for all columns.
     fn compare(&self) -> Vec<Box<dyn Fn(&R, &R) -> std::cmp::Ordering + '_>>;
+    /// Returns group key functions for all columns.
+    fn group_key(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>>;
+    /// Returns the default widths of all columns.
+    fn default_widths(&self) -> Vec<Option<f64>>;
+    /// Returns the minimum widths of all columns.
+    fn min_widths(&self) -> Vec<Option<f64>>;
+    /// Returns the maximum widths of all columns.
+    fn max_widths(&self) -> Vec<Option<f64>>;
 }
 
 /// A serializable header with export configuration.
@@ -83,22 +105,49 @@ macro_rules! columns {
             fn column_names(&self) -> Vec<String> {
                 vec![$(self.$number.column_name()),*]
             }
-            fn headers(&self) -> Vec<Box<dyn Fn(&TableContext<Self>, Vec<Attribute>) -> Element + '_>> {
+            fn headers(&self) -> Vec<Box<dyn Fn(ColumnContext, Vec<Attribute>) -> Element + '_>> {
                 vec![$(Box::new(move |context, attributes| {
-                    self.$number.render_header(context.data.column_context($number), attributes)
+                    self.$number.render_header(context, attributes)
                 })),*]
             }
-            fn columns(&self) -> Vec<Box<dyn Fn(&TableContext<Self>, &R, Vec<Attribute>) -> Element + '_>> {
+            fn footers(
+                &self,
+            ) -> Vec<Box<dyn Fn(ColumnContext, &mut dyn Iterator<Item = &R>, Vec<Attribute>) -> Element + '_>> {
+                vec![$(Box::new(move |context, rows, attributes| {
+                    self.$number.render_footer(context, rows, attributes)
+                })),*]
+            }
+            fn columns(&self) -> Vec<Box<dyn Fn(ColumnContext, &R, Vec<Attribute>) -> Element + '_>> {
                 vec![$(Box::new(move |context, row, attributes| {
-                    self.$number.render_cell(context.data.column_context($number), row, attributes)
+                    self.$number.render_cell(context, row, attributes)
                 })),*]
             }
             fn filter(&self, row: &R) -> bool {
                 $(self.$number.filter(row) &&)* true
             }
+            fn filters(&self) -> Vec<Option<Box<dyn Fn(&R) -> bool + '_>>> {
+                vec![$(self.$number.is_filter_active().then(|| -> Box<dyn Fn(&R) -> bool + '_> {
+                    Box::new(move |row| self.$number.filter(row))
+                })),*]
+            }
+            fn search_text(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                vec![$(Box::new(move |row| self.$number.search_text(row))),*]
+            }
             fn compare(&self) -> Vec<Box<dyn Fn(&R, &R) -> std::cmp::Ordering + '_>> {
                 vec![$(Box::new(move |a, b| self.$number.compare(a, b))),*]
             }
+            fn group_key(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                vec![$(Box::new(move |row| self.$number.group_key(row))),*]
+            }
+            fn default_widths(&self) -> Vec<Option<f64>> {
+                vec![$(self.$number.default_width()),*]
+            }
+            fn min_widths(&self) -> Vec<Option<f64>> {
+                vec![$(self.$number.min_width()),*]
+            }
+            fn max_widths(&self) -> Vec<Option<f64>> {
+                vec![$(self.$number.max_width()),*]
+            }
         }
         #[cfg(feature = "export")]
         serialize_columns!($($number => $column),*);
@@ -139,3 +188,281 @@ columns!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I)
 columns!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J);
 columns!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K);
 columns!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K, 11 => L);
+
+/// Trait for values that can be turned into the [`Columns`] of a table, accepted by
+/// [`use_tabular`](crate::use_tabular).
+///
+/// Besides the [`Columns`] themselves, it is implemented for:
+///
+/// - Tuples of 13 to 32 [`TableColumn`]s, for tables wider than a [`Columns`] tuple.
+/// - Tuples of 1 to 12 column sets, each being anything accepted here, e.g.
+///   `((A, B, C), (D, E))`. The sets are flattened into one table in order, so the columns above
+///   get the indices 0 to 4 and sort, reorder, hide and export like those of `(A, B, C, D, E)`.
+///
+/// A set tuple cannot mix sets and single columns: wrap a single column as `(C,)`.
+///
+/// The `M` parameter only tells the implementations apart and is inferred.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Entry { id: u32 }
+/// # impl Row for Entry {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col(&'static str);
+/// # impl TableColumn<Entry> for Col {
+/// #     fn column_name(&self) -> String { self.0.into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &Entry, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let entries = use_signal(|| vec![Entry { id: 1 }]);
+/// // A reusable set of columns shared by several tables
+/// let audit = (Col("created_at"), Col("created_by"), Col("updated_at"), Col("updated_by"));
+/// // "created_at" is the column with index 2
+/// let data = use_tabular(((Col("id"), Col("action")), audit), entries.into());
+/// #     rsx! { table {} }
+/// # }
+/// ```
+pub trait IntoColumns<R: Row, M> {
+    /// The columns of the table.
+    type Columns: Columns<R>;
+    /// Turns this value into the columns of the table.
+    fn into_columns(self) -> Self::Columns;
+}
+
+impl<R: Row, C: Columns<R>> IntoColumns<R, ()> for C {
+    type Columns = C;
+    fn into_columns(self) -> C {
+        self
+    }
+}
+
+/// Marks the [`IntoColumns`] implementation for tuples of column sets.
+#[doc(hidden)]
+pub struct NestedMarker<M>(M);
+
+/// Marks the [`IntoColumns`] implementation for tuples of more than 12 columns.
+#[doc(hidden)]
+pub struct WideMarker;
+
+/// Column sets flattened into the columns of one table, created by [`IntoColumns`].
+///
+/// Wraps a tuple of [`Columns`]; the columns of the first set come first.
+#[derive(Clone, PartialEq, Debug)]
+pub struct NestedColumns<T>(T);
+
+macro_rules! nested_columns {
+    ($($number:tt => $set:ident $marker:ident),*) => {
+        impl<$($set: Columns<R>),*, R: Row> Columns<R> for NestedColumns<($($set),*,)> {
+            fn column_names(&self) -> Vec<String> {
+                let mut names = Vec::new();
+                $(names.extend(self.0.$number.column_names());)*
+                names
+            }
+            fn headers(&self) -> Vec<Box<dyn Fn(ColumnContext, Vec<Attribute>) -> Element + '_>> {
+                let mut headers = Vec::new();
+                $(headers.extend(self.0.$number.headers());)*
+                headers
+            }
+            fn footers(
+                &self,
+            ) -> Vec<Box<dyn Fn(ColumnContext, &mut dyn Iterator<Item = &R>, Vec<Attribute>) -> Element + '_>> {
+                let mut footers = Vec::new();
+                $(footers.extend(self.0.$number.footers());)*
+                footers
+            }
+            fn columns(&self) -> Vec<Box<dyn Fn(ColumnContext, &R, Vec<Attribute>) -> Element + '_>> {
+                let mut columns = Vec::new();
+                $(columns.extend(self.0.$number.columns());)*
+                columns
+            }
+            fn filter(&self, row: &R) -> bool {
+                $(self.0.$number.filter(row) &&)* true
+            }
+            fn filters(&self) -> Vec<Option<Box<dyn Fn(&R) -> bool + '_>>> {
+                let mut filters = Vec::new();
+                $(filters.extend(self.0.$number.filters());)*
+                filters
+            }
+            fn search_text(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                let mut search_text = Vec::new();
+                $(search_text.extend(self.0.$number.search_text());)*
+                search_text
+            }
+            fn compare(&self) -> Vec<Box<dyn Fn(&R, &R) -> std::cmp::Ordering + '_>> {
+                let mut compare = Vec::new();
+                $(compare.extend(self.0.$number.compare());)*
+                compare
+            }
+            fn group_key(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                let mut group_key = Vec::new();
+                $(group_key.extend(self.0.$number.group_key());)*
+                group_key
+            }
+            fn default_widths(&self) -> Vec<Option<f64>> {
+                let mut widths = Vec::new();
+                $(widths.extend(self.0.$number.default_widths());)*
+                widths
+            }
+            fn min_widths(&self) -> Vec<Option<f64>> {
+                let mut widths = Vec::new();
+                $(widths.extend(self.0.$number.min_widths());)*
+                widths
+            }
+            fn max_widths(&self) -> Vec<Option<f64>> {
+                let mut widths = Vec::new();
+                $(widths.extend(self.0.$number.max_widths());)*
+                widths
+            }
+        }
+        impl<$($set: IntoColumns<R, $marker>, $marker),*, R: Row> IntoColumns<R, NestedMarker<($($marker),*,)>>
+            for ($($set),*,)
+        {
+            type Columns = NestedColumns<($($set::Columns),*,)>;
+            fn into_columns(self) -> Self::Columns {
+                NestedColumns(($(self.$number.into_columns()),*,))
+            }
+        }
+        #[cfg(feature = "export")]
+        serialize_nested_columns!($($number => $set),*);
+    }
+}
+
+#[cfg(feature = "export")]
+macro_rules! serialize_nested_columns {
+    ($($number:tt => $set:ident),*) => {
+        impl<$($set: SerializableColumns<R>),*, R: Row> SerializableColumns<R> for NestedColumns<($($set),*,)> {
+            fn serialize_headers(&self) -> Vec<SerializableHeader<'_>> {
+                let mut headers = Vec::new();
+                $(headers.extend(self.0.$number.serialize_headers());)*
+                headers
+            }
+            fn serialize_cell<Ex: Exporter>(&self) -> Vec<SerializableCell<'_, R, Ex>> {
+                let mut cells = Vec::new();
+                $(cells.extend(self.0.$number.serialize_cell::<Ex>());)*
+                cells
+            }
+        }
+    }
+}
+
+/// Implements [`IntoColumns`] for a flat tuple of columns, split into the given chunks of at most
+/// 12 columns.
+macro_rules! wide_columns {
+    ($(($($number:tt => $column:ident),*)),*) => {
+        impl<$($($column: TableColumn<R>),*),*, R: Row> IntoColumns<R, WideMarker> for ($($($column),*),*) {
+            type Columns = NestedColumns<($(($($column),*,)),*)>;
+            fn into_columns(self) -> Self::Columns {
+                NestedColumns(($(($(self.$number),*,)),*))
+            }
+        }
+    }
+}
+
+nested_columns!(0 => S0 M0);
+nested_columns!(0 => S0 M0, 1 => S1 M1);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6, 7 => S7 M7);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6, 7 => S7 M7, 8 => S8 M8);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6, 7 => S7 M7, 8 => S8 M8, 9 => S9 M9);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6, 7 => S7 M7, 8 => S8 M8, 9 => S9 M9, 10 => S10 M10);
+nested_columns!(0 => S0 M0, 1 => S1 M1, 2 => S2 M2, 3 => S3 M3, 4 => S4 M4, 5 => S5 M5, 6 => S6 M6, 7 => S7 M7, 8 => S8 M8, 9 => S9 M9, 10 => S10 M10, 11 => S11 M11);
+
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26, 27 => A27)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26, 27 => A27, 28 => A28)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26, 27 => A27, 28 => A28, 29 => A29)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26, 27 => A27, 28 => A28, 29 => A29, 30 => A30)
+);
+wide_columns!(
+    (0 => A0, 1 => A1, 2 => A2, 3 => A3, 4 => A4, 5 => A5, 6 => A6, 7 => A7, 8 => A8, 9 => A9, 10 => A10, 11 => A11),
+    (12 => A12, 13 => A13, 14 => A14, 15 => A15, 16 => A16, 17 => A17, 18 => A18, 19 => A19, 20 => A20, 21 => A21, 22 => A22, 23 => A23),
+    (24 => A24, 25 => A25, 26 => A26, 27 => A27, 28 => A28, 29 => A29, 30 => A30, 31 => A31)
+);
@@ -1,5 +1,10 @@
-use crate::{Columns, Row, RowData, TableContext, TableData};
+use crate::{
+    ColumnContext, ColumnGroups, Columns, IntoColumns, PinState, Row, RowData, TableContext,
+    TableData, TableStorage, VirtualWindow,
+};
+use dioxus::core::current_scope_id;
 use dioxus::prelude::*;
+use std::rc::Rc;
 
 /// Creates a reactive table with the given columns and rows.
 ///
@@ -8,9 +13,16 @@ use dioxus::prelude::*;
 ///
 /// # Parameters
 ///
-/// - `columns`: A tuple of columns implementing [`TableColumn`](crate::TableColumn)
+/// - `columns`: A tuple of columns implementing [`TableColumn`](crate::TableColumn), or of
+///   column sets (see [`IntoColumns`])
 /// - `rows`: A reactive signal containing the row data
 ///
+/// The columns are compared with those of the previous render, and replace them if they changed.
+/// When the column names change, e.g. once a schema fetched after mount arrives, the sorts,
+/// order, visibility, pins and widths carry over to the columns with the same names, like a
+/// restored [`TableState`](crate::TableState). Columns should therefore compare equal when
+/// nothing changed: create their signals with hooks like `use_signal`, not `Signal::new`.
+///
 /// # Example
 ///
 /// ```
@@ -100,14 +112,81 @@ use dioxus::prelude::*;
 /// #     let users = use_signal(|| vec![
 /// #         User { id: 1, name: "Alice".to_string() },
 /// #     ]);
-/// // Use tuple for multiple columns (supports up to 12 columns)
+/// // Use tuple for multiple columns (see `IntoColumns` for more than 12 columns)
 /// let data = use_tabular((IdColumn, NameColumn), users.into());
 /// #     rsx! { table {} }
 /// # }
 /// ```
-pub fn use_tabular<C: Columns<R>, R: Row>(columns: C, rows: ReadSignal<Vec<R>>) -> TableData<C, R> {
-    let context = TableContext::use_table_context(columns);
-    context.table_data(rows)
+pub fn use_tabular<I: IntoColumns<R, M>, M, R: Row>(
+    columns: I,
+    rows: ReadSignal<Vec<R>>,
+) -> TableData<I::Columns, R> {
+    let context = TableContext::use_table_context(columns.into_columns());
+    let data = context.table_data(rows);
+    // Drop selected and expanded keys whose rows were removed
+    use_effect(move || data.prune_removed_rows());
+    data
+}
+
+/// Like [`use_tabular`], but persists the table layout in a [`TableStorage`].
+///
+/// On the first render, the sorts and the column order and visibility are restored from the
+/// layout saved under `table_id`, if any. Afterwards, the layout is saved whenever it changes,
+/// except during a column resize (see [`ColumnContext::begin_resize`]), which is saved when it
+/// ends.
+/// See [`TableState`](crate::TableState) for how saved layouts adapt to added or removed columns.
+///
+/// `storage` and `table_id` are only used from the first render on; later values are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let users = use_signal(|| vec![User { id: 1 }]);
+/// // Share one storage between the tables of the app
+/// let storage = use_context::<MemoryStorage>();
+/// let data = use_tabular_with_storage((Col,), users.into(), storage, "users");
+/// #     rsx! { table {} }
+/// # }
+/// ```
+pub fn use_tabular_with_storage<I: IntoColumns<R, M>, M, R: Row>(
+    columns: I,
+    rows: ReadSignal<Vec<R>>,
+    storage: impl TableStorage,
+    table_id: impl Into<String>,
+) -> TableData<I::Columns, R> {
+    let storage = use_hook(|| Rc::new(storage));
+    let table_id = use_hook(|| Rc::new(table_id.into()));
+    let context = {
+        let storage = storage.clone();
+        let table_id = table_id.clone();
+        TableContext::use_table_context_with_state(columns.into_columns(), move || {
+            storage.load(&table_id)
+        })
+    };
+    let data = context.table_data(rows);
+    use_effect(move || data.prune_removed_rows());
+    use_effect(move || {
+        // Save once a column resize is over rather than on every move
+        if !data.context.data.is_resizing() {
+            storage.save(&table_id, &data.context.snapshot());
+        }
+    });
+    data
 }
 
 /// Renders table headers for all visible columns.
@@ -196,6 +275,260 @@ pub fn TableHeaders<C: Columns<R>, R: Row>(
     }
 }
 
+/// Renders a header row cell for each group of adjacent visible columns.
+///
+/// Each `<th>` spans the visible columns of one group with a `colspan`, following the current
+/// column order: a group is split when reordering separates its members, and shrinks or
+/// disappears when they are hidden. Adjacent columns outside of any group share one empty `<th>`.
+/// Groups also split at the edges of pinned regions, and the headers of pinned columns get the
+/// same sticky positioning as their columns.
+///
+/// # Props
+///
+/// - `data`: The table data from [`use_tabular`]
+/// - `groups`: The column groups of this header row
+/// - Additional HTML attributes can be spread onto each `<th>` element
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Report { id: u32 }
+/// # impl Row for Report {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col(&'static str);
+/// # impl TableColumn<Report> for Col {
+/// #     fn column_name(&self) -> String { self.0.into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &Report, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let reports = use_signal(|| vec![Report { id: 1 }]);
+/// let columns = (Col("q1_revenue"), Col("q1_cost"), Col("q2_revenue"), Col("q2_cost"));
+/// let data = use_tabular(columns, reports.into());
+/// let years = ColumnGroups::new().with_group("2024", ["q1_revenue", "q1_cost", "q2_revenue", "q2_cost"]);
+/// let quarters = ColumnGroups::new()
+///     .with_group("Q1", ["q1_revenue", "q1_cost"])
+///     .with_group("Q2", ["q2_revenue", "q2_cost"]);
+///
+/// rsx! {
+///     thead {
+///         tr { TableColumnGroups { data, groups: years } }
+///         tr { TableColumnGroups { data, groups: quarters, class: "group" } }
+///         tr { TableHeaders { data } }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn TableColumnGroups<C: Columns<R>, R: Row>(
+    data: TableData<C, R>,
+    groups: ColumnGroups,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+) -> Element {
+    let context = data.context.data;
+    let headers = data
+        .context
+        .column_group_spans(&groups)
+        .into_iter()
+        .map(|span| {
+            // Right-pinned headers stick by their last column, others by their first one
+            let first = span.columns[0];
+            let last = span.columns[span.colspan() - 1];
+            let pinned = match context.column_pin_state(first) {
+                PinState::Right => last,
+                _ => first,
+            };
+            let mut attributes = attributes.clone();
+            attributes.extend(context.pin_attributes(pinned));
+            (
+                first,
+                span.colspan(),
+                span.label.unwrap_or_default(),
+                attributes,
+            )
+        });
+    rsx! {
+        for (key, colspan, label, attributes) in headers {
+            th { key: "{key}", colspan: "{colspan}", ..attributes, "{label}" }
+        }
+    }
+}
+
+/// Renders a handle resizing a column when dragged, to be placed inside its header.
+///
+/// Dragging the handle sets the column width with [`ColumnContext::set_width`], starting from
+/// its current [`width`](ColumnContext::width). A column without a width starts from the
+/// measured width of its header, as long as the header spreads the attributes passed to
+/// [`render_header`](crate::TableColumn::render_header). The handle captures the pointer with
+/// [`document::eval`] while dragging, so the drag goes on when the pointer leaves the handle or
+/// the window, and releasing it does not click the header. Renderers that can't run scripts
+/// only follow the pointer while it is over the handle.
+///
+/// The handle is an empty `<div>` without any style: place it with the spread attributes, e.g.
+/// at the right edge of a header with `position: relative`.
+///
+/// # Props
+///
+/// - `context`: The column context received by [`render_header`](crate::TableColumn::render_header)
+/// - Additional HTML attributes can be spread onto the handle `<div>` element
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// #[derive(Clone, PartialEq)]
+/// struct NameColumn;
+///
+/// impl TableColumn<User> for NameColumn {
+///     fn column_name(&self) -> String {
+///         "name".into()
+///     }
+///
+///     fn render_header(&self, context: ColumnContext, attributes: Vec<Attribute>) -> Element {
+///         rsx! {
+///             th { position: "relative", ..attributes,
+///                 "Name"
+///                 ColumnResizeHandle {
+///                     context,
+///                     style: "position: absolute; top: 0; right: 0; bottom: 0; width: 4px; cursor: col-resize;",
+///                 }
+///             }
+///         }
+///     }
+///
+///     fn render_cell(&self, _: ColumnContext, _: &User, attributes: Vec<Attribute>) -> Element {
+///         rsx! { td { ..attributes } }
+///     }
+///
+///     fn default_width(&self) -> Option<f64> {
+///         Some(160.0)
+///     }
+///
+///     fn min_width(&self) -> Option<f64> {
+///         Some(60.0)
+///     }
+/// }
+/// ```
+#[component]
+pub fn ColumnResizeHandle(
+    context: ColumnContext,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+) -> Element {
+    // The pointer position and the column width when the drag started. The width is `None`
+    // while the header of a column sized by the browser is being measured.
+    let mut drag = use_signal(|| None::<(f64, Option<f64>)>);
+    // Identifies the handle element for the script capturing the pointer
+    let handle_id = current_scope_id().0;
+
+    rsx! {
+        div {
+            "data-resize-handle": "{handle_id}",
+            onpointerdown: move |e: PointerEvent| {
+                e.prevent_default();
+                e.stop_propagation();
+                let width = context.width();
+                drag.set(Some((e.client_coordinates().x, width)));
+                context.begin_resize();
+                // Renderers without scripts still resize while the pointer is over the handle
+                let pointer_id = e.pointer_id();
+                let _ = document::eval(&format!(
+                    "document.querySelector('[data-resize-handle=\"{handle_id}\"]')?.setPointerCapture({pointer_id})"
+                ));
+                if width.is_none() {
+                    spawn(async move {
+                        let width = context.measure_width().await.unwrap_or(0.0);
+                        if let Some((_, start_width @ None)) = drag.write().as_mut() {
+                            *start_width = Some(width);
+                        }
+                    });
+                }
+            },
+            onpointermove: move |e: PointerEvent| {
+                if let Some((start_x, Some(start_width))) = drag() {
+                    context.set_width(start_width + e.client_coordinates().x - start_x);
+                }
+            },
+            onpointerup: move |_| {
+                drag.set(None);
+                context.end_resize();
+            },
+            onlostpointercapture: move |_| {
+                drag.set(None);
+                context.end_resize();
+            },
+            onclick: move |e: MouseEvent| e.stop_propagation(),
+            ..attributes,
+        }
+    }
+}
+
+/// Renders table footers for all visible columns.
+///
+/// Each column renders its footer with [`TableColumn::render_footer`](crate::TableColumn::render_footer),
+/// which receives all filtered rows (ignoring pagination) to compute aggregates like totals.
+/// Like [`TableHeaders`], it follows column reordering and visibility.
+///
+/// # Props
+///
+/// - `data`: The table data from [`use_tabular`]
+/// - Additional HTML attributes can be spread onto each footer element
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let users = use_signal(|| vec![User { id: 1 }]);
+/// #     let data = use_tabular((Col,), users.into());
+/// rsx! {
+///     table {
+///         thead { tr { TableHeaders { data } } }
+///         tfoot {
+///             tr {
+///                 // Renders all column footers
+///                 TableFooters { data, class: "footer-cell" }
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn TableFooters<C: Columns<R>, R: Row>(
+    data: TableData<C, R>,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+) -> Element {
+    rsx! {
+        for footer in data.footers() {
+            Fragment { key: "{footer.key()}", {footer.render(attributes.clone())} }
+        }
+    }
+}
+
 /// Renders table cells for a single row across all visible columns.
 ///
 /// This component iterates through the columns and renders each cell for the given row.
@@ -287,3 +620,173 @@ pub fn TableCells<C: Columns<R>, R: Row>(
         }
     }
 }
+
+/// Renders a full-width detail row below a table row while it is expanded.
+///
+/// Nothing is rendered unless [`RowData::is_expanded`] returns true. The single `<td>` spans
+/// all visible columns, so the detail row keeps fitting the table when columns are hidden.
+/// Toggle it with [`RowData::toggle_expanded`].
+///
+/// # Props
+///
+/// - `row`: A row from iterating over `data.rows()`
+/// - `children`: The content of the detail row
+/// - Additional HTML attributes can be spread onto the `<td>` element
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let users = use_signal(|| vec![User { id: 1 }]);
+/// #     let data = use_tabular((Col,), users.into());
+/// rsx! {
+///     tbody {
+///         for row in data.rows() {
+///             Fragment { key: "{row.key()}",
+///                 tr {
+///                     onclick: move |_| row.toggle_expanded(),
+///                     TableCells { row }
+///                 }
+///                 TableDetailRow { row, class: "detail",
+///                     "Details of user {row.data().read().id}"
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn TableDetailRow<C: Columns<R>, R: Row>(
+    row: RowData<C, R>,
+    children: Element,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+) -> Element {
+    if !row.is_expanded() {
+        return rsx! {};
+    }
+    let colspan = row.visible_column_count();
+    rsx! {
+        tr {
+            td { colspan: "{colspan}", ..attributes, {children} }
+        }
+    }
+}
+
+/// Renders a `<tbody>` containing only the rows inside the scroll viewport.
+///
+/// Rows outside the viewport are replaced by two spacer rows, each holding one cell spanning the
+/// visible columns, so the scroll container keeps the full height of the table while only a
+/// window of rows is rendered. Rows come from [`TableData::rows_in_range`], so filtering, sorting
+/// and pagination still apply.
+///
+/// Every row must be `row_height` pixels tall. The parent owns the scroll container and passes
+/// its `scroll_top` and `viewport_height`, typically from an `onscroll` handler.
+///
+/// # Props
+///
+/// - `data`: The table data from [`use_tabular`]
+/// - `row_height`: The height of every row in pixels
+/// - `scroll_top`: The current `scrollTop` of the scroll container in pixels
+/// - `viewport_height`: The height of the scroll container in pixels
+/// - `overscan`: Extra rows rendered above and below the viewport (default: 3)
+/// - Additional HTML attributes can be spread onto each row's `<tr>` element
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// let users = use_signal(|| (0..50_000).map(|id| User { id }).collect::<Vec<_>>());
+/// let data = use_tabular((Col,), users.into());
+/// let mut scroll_top = use_signal(|| 0.0);
+///
+/// rsx! {
+///     div {
+///         style: "height: 400px; overflow-y: auto;",
+///         onscroll: move |e| scroll_top.set(e.scroll_top()),
+///         table {
+///             thead { tr { TableHeaders { data } } }
+///             VirtualTableBody {
+///                 data,
+///                 row_height: 24.0,
+///                 scroll_top: scroll_top(),
+///                 viewport_height: 400.0,
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn VirtualTableBody<C: Columns<R>, R: Row>(
+    data: TableData<C, R>,
+    row_height: f64,
+    scroll_top: f64,
+    viewport_height: f64,
+    #[props(default = 3)] overscan: usize,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+) -> Element {
+    let window = VirtualWindow::new(row_height).with_overscan(overscan);
+    let row_count = data.row_count();
+    let range = window.range(scroll_top, viewport_height, row_count);
+    let top_spacer = window.offset(range.start);
+    let bottom_spacer = window.total_height(row_count) - window.offset(range.end);
+    let row_height = window.row_height();
+    // Spacers are single cells, as browsers may collapse rows without cells
+    let colspan = data.context.data.num_visible_columns().max(1);
+
+    rsx! {
+        tbody {
+            tr {
+                key: "virtual-top-spacer",
+                td {
+                    colspan: "{colspan}",
+                    style: "height: {top_spacer}px; padding: 0; border: 0;",
+                }
+            }
+            for row in data.rows_in_range(range) {
+                tr {
+                    key: "{row.key()}",
+                    height: "{row_height}px",
+                    ..attributes.clone(),
+                    TableCells { row }
+                }
+            }
+            tr {
+                key: "virtual-bottom-spacer",
+                td {
+                    colspan: "{colspan}",
+                    style: "height: {bottom_spacer}px; padding: 0; border: 0;",
+                }
+            }
+        }
+    }
+}
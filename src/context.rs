@@ -1,13 +1,39 @@
+use dioxus::core::current_scope_id;
 use dioxus::prelude::*;
 
 use crate::{Columns, Row};
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
 
+mod column_groups;
 mod column_order;
-pub use column_order::ColumnOrder;
+mod column_widths;
+mod filter_expr;
+mod grouping;
+mod pagination;
+mod rows_cache;
+mod search;
+mod selection;
+mod table_query;
+mod table_state;
+pub use column_groups::{ColumnGroupSpan, ColumnGroups};
+pub use column_order::{ColumnOrder, PinState};
+use column_widths::ColumnWidths;
+pub use filter_expr::FilterExpr;
+pub use grouping::GroupPath;
+use grouping::{GroupedItem, Groups, Visible};
+pub use pagination::Pagination;
+use rows_cache::{FilteredRows, RowsCache};
+pub use search::{Search, SearchOptions};
+pub use selection::{Selection, SelectionGesture, SelectionMode};
+pub use table_query::TableQuery;
+pub use table_state::{SortState, TableState};
 
 /// The direction of sorting.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SortDirection {
     /// Sort in ascending order (A to Z, 0 to 9).
     Ascending,
@@ -104,6 +130,26 @@ pub(crate) struct TableContextData {
     column_names: Signal<Vec<String>>,
     // Manages the order and visibility of columns.
     column_order: Signal<ColumnOrder>,
+    // The widths of columns, within their limits.
+    column_widths: Signal<ColumnWidths>,
+    // How the column filters are combined, or `None` to require all of them.
+    filter_expr: Signal<Option<FilterExpr>>,
+    // The table-wide search query.
+    search: Signal<Search>,
+    // The page size and current page.
+    pagination: Signal<Pagination>,
+    // The selected rows, keyed by `Row::key`.
+    selection: Signal<Selection>,
+    // The rows showing their detail row, keyed by `Row::key`.
+    expanded: Signal<HashSet<String>>,
+    // The columns rows are grouped by, from the outermost group inwards.
+    group_by: Signal<Vec<usize>>,
+    // The collapsed groups, kept apart from `group_by` so that collapsing does not re-run the pipeline.
+    collapsed_groups: Signal<HashSet<GroupPath>>,
+    // The mounted header elements, to measure columns sized by the browser.
+    header_elements: CopyValue<HashMap<usize, Rc<MountedData>>>,
+    // Whether a column is being resized, holding back saving the layout until it is done.
+    resizing: Signal<bool>,
 }
 
 #[derive(PartialEq)]
@@ -111,6 +157,8 @@ pub struct TableContext<C: 'static> {
     pub(crate) data: TableContextData,
     /// Does not need to be Signal, but for Copy trait.
     pub(crate) columns: Signal<C>,
+    /// Memoized output of the filter/sort pipeline.
+    pub(crate) rows_cache: CopyValue<RowsCache>,
 }
 
 impl<C: 'static> Copy for TableContext<C> {}
@@ -127,19 +175,100 @@ impl<C> TableContext<C> {
         C: Columns<R>,
         R: Row,
     {
-        let sorts = use_signal(Vec::new);
+        Self::use_table_context_with_state(columns, || None)
+    }
+
+    /// Like [`use_table_context`](Self::use_table_context), but initializes the sorts and the
+    /// column layout from the [`TableState`] returned by `state`.
+    ///
+    /// `state` is only called on the first render.
+    pub fn use_table_context_with_state<R>(
+        columns: C,
+        state: impl FnOnce() -> Option<TableState>,
+    ) -> Self
+    where
+        C: Columns<R>,
+        R: Row,
+    {
         let column_names = use_signal(|| columns.column_names());
         let total_columns = column_names.read().len();
-        let column_order = use_signal(|| ColumnOrder::new(total_columns));
-        let columns = use_signal(|| columns);
-        Self {
+        let initial = use_hook(state);
+        let sorts = use_signal(|| {
+            initial
+                .as_ref()
+                .map(|state| state.resolve(&column_names.peek()).0)
+                .unwrap_or_default()
+        });
+        let column_order = use_signal(|| {
+            let mut column_order = ColumnOrder::new(total_columns);
+            if let Some(state) = &initial {
+                let column_names = column_names.peek();
+                column_order.set_pins(state.resolve_pins(&column_names));
+                column_order.set_order(state.resolve(&column_names).1);
+            }
+            column_order
+        });
+        let column_widths = use_signal(|| {
+            let mut column_widths = ColumnWidths::new(
+                columns.default_widths(),
+                columns.min_widths(),
+                columns.max_widths(),
+            );
+            if let Some(state) = &initial {
+                for (col, width) in state.resolve_widths(&column_names.peek()) {
+                    column_widths.set(col, width);
+                }
+            }
+            column_widths
+        });
+        let filter_expr = use_signal(|| None);
+        let search = use_signal(Search::default);
+        let pagination = use_signal(Pagination::default);
+        let selection = use_signal(Selection::default);
+        let expanded = use_signal(HashSet::new);
+        let group_by = use_signal(Vec::new);
+        let collapsed_groups = use_signal(HashSet::new);
+        let header_elements = use_hook(|| CopyValue::new(HashMap::new()));
+        let resizing = use_signal(|| false);
+        let mut new_columns = Some(columns);
+        let mut columns = use_signal(|| new_columns.take().unwrap());
+        let rows_cache = use_hook(|| CopyValue::new(RowsCache::new(current_scope_id())));
+        let context = Self {
             data: TableContextData {
                 sorts,
                 column_names,
                 column_order,
+                column_widths,
+                filter_expr,
+                search,
+                pagination,
+                selection,
+                expanded,
+                group_by,
+                collapsed_groups,
+                header_elements,
+                resizing,
             },
             columns,
+            rows_cache,
+        };
+        // Columns passed on later renders replace the first ones if they changed, e.g. once a
+        // schema fetched after mount arrives
+        if let Some(new_columns) = new_columns
+            && *columns.peek() != new_columns
+        {
+            let column_names = new_columns.column_names();
+            if *context.data.column_names.peek() != column_names {
+                let widths = ColumnWidths::new(
+                    new_columns.default_widths(),
+                    new_columns.min_widths(),
+                    new_columns.max_widths(),
+                );
+                context.data.set_columns(column_names, widths);
+            }
+            columns.set(new_columns);
         }
+        context
     }
 
     pub fn table_data<R>(self, rows: ReadSignal<Vec<R>>) -> TableData<C, R>
@@ -157,6 +286,57 @@ impl<C> TableContext<C> {
         self.data.get_column_order()
     }
 
+    /// Returns the cells of a column group header row for the visible columns, in display order.
+    ///
+    /// See [`TableColumnGroups`](crate::TableColumnGroups).
+    pub fn column_group_spans(&self, groups: &ColumnGroups) -> Vec<ColumnGroupSpan> {
+        let column_names = self.data.column_names.read();
+        groups.spans(&column_names, &self.data.column_order.read())
+    }
+
+    /// Captures the current sorts, column order, visibility, pins and widths, identified by
+    /// column name.
+    pub fn snapshot(&self) -> TableState {
+        self.data.snapshot()
+    }
+
+    /// Restores sorts, column order, visibility, pins and widths from a [`TableState`], and goes
+    /// back to the first page.
+    ///
+    /// Unknown column names are ignored, and columns missing from the state are shown after the others.
+    pub fn restore(&self, state: &TableState) {
+        self.data.restore(state);
+    }
+
+    /// Encodes the current sorts and column layout as a query string like
+    /// `sort=-priority,title&cols=title,status`. See [`TableQuery`].
+    pub fn to_query_string(&self) -> String {
+        let state = self.snapshot();
+        let column_names = self.data.column_names.read();
+        TableQuery::from_state(&state, &column_names).to_query_string()
+    }
+
+    /// Applies the sorts and column layout of a query string produced by
+    /// [`to_query_string`](Self::to_query_string).
+    ///
+    /// Other parameters and unknown column names are ignored. A missing `sort` clears the sorts,
+    /// and a missing `cols` restores the default layout. Pins and widths are kept.
+    pub fn apply_query_string(&self, query: &str) {
+        let column_names = self.data.column_names.peek().clone();
+        let mut state = TableQuery::parse(query).into_state(&column_names);
+        // Query strings don't carry pins and widths, so keep the current ones
+        let current = TableState::capture(
+            &column_names,
+            &self.data.sorts.peek(),
+            &self.data.column_order.peek(),
+            &self.data.column_widths.peek(),
+        );
+        state.pinned_left = current.pinned_left;
+        state.pinned_right = current.pinned_right;
+        state.widths = current.widths;
+        self.restore(&state);
+    }
+
     pub fn headers<R>(self) -> impl Iterator<Item = HeaderData<C, R>>
     where
         C: Columns<R>,
@@ -184,6 +364,20 @@ impl<C> TableContext<C> {
         })
     }
 
+    /// Returns an iterator over the footers of the visible columns, aggregating the filtered `rows`.
+    pub fn footers<R>(self, rows: ReadSignal<Vec<R>>) -> impl Iterator<Item = FooterData<C, R>>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let order = self.get_column_order();
+        order.into_iter().map(move |column_index| FooterData {
+            context: self,
+            rows,
+            column_index,
+        })
+    }
+
     pub fn cells<R>(self, row: RowData<C, R>) -> impl Iterator<Item = CellData<C, R>>
     where
         C: Columns<R>,
@@ -197,18 +391,303 @@ impl<C> TableContext<C> {
         })
     }
 
+    /// Returns an iterator over the filtered and sorted rows of the current page.
+    ///
+    /// The filtered and sorted indices are memoized, so repeated calls only cost
+    /// O(visible rows) until the rows, the sorts or a signal read by the columns changes.
+    /// If pagination is disabled (the default), all filtered rows are returned.
+    ///
+    /// With group-by columns, these are the rows among the items of
+    /// [`grouped_rows`](Self::grouped_rows): rows of collapsed groups and rows pushed to another
+    /// page by group headers are left out.
     pub fn rows<R>(self, rows: ReadSignal<Vec<R>>) -> impl Iterator<Item = RowData<C, R>>
     where
         C: Columns<R>,
         R: Row,
     {
-        let rows_data = rows.read();
+        let indices = self.page_indices(rows);
+        self.row_data(rows, indices)
+    }
+
+    /// Returns an iterator over the rows at positions `range` of [`rows`](Self::rows).
+    ///
+    /// Only the rows inside the range are materialized, which makes it suitable for
+    /// rendering a window of a large table. The range is saturated to the available rows.
+    pub fn rows_in_range<R>(
+        self,
+        rows: ReadSignal<Vec<R>>,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = RowData<C, R>>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let mut indices = self.page_indices(rows);
+        let end = range.end.min(indices.len());
+        let start = range.start.min(end);
+        indices.truncate(end);
+        indices.drain(..start);
+        self.row_data(rows, indices)
+    }
+
+    /// Returns the number of rows yielded by [`rows`](Self::rows), i.e. the rows on the current page.
+    pub fn row_count<R>(self, rows: ReadSignal<Vec<R>>) -> usize
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        self.page_indices(rows).len()
+    }
+
+    /// Returns the indices of the rows on the current page. With group-by columns, pages are
+    /// made of the visible items of [`grouped_rows`](Self::grouped_rows), and only their rows
+    /// are returned.
+    fn page_indices<R>(self, rows: ReadSignal<Vec<R>>) -> Vec<usize>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let pagination = *self.data.pagination.read();
+        let grouped = !self.data.group_by.read().is_empty();
+        let collapsed = self.data.collapsed_groups.read();
+        self.with_filtered_rows(rows, |filtered| {
+            if grouped {
+                let visible = filtered.groups.visible(&collapsed);
+                visible[pagination.range(visible.len())]
+                    .iter()
+                    .filter_map(|&item| match item {
+                        Visible::Row(pos) => Some(filtered.indices[pos]),
+                        Visible::Header { .. } => None,
+                    })
+                    .collect()
+            } else {
+                filtered.indices[pagination.range(filtered.indices.len())].to_vec()
+            }
+        })
+    }
+
+    /// Returns an iterator over all filtered and sorted rows, ignoring pagination.
+    pub fn filtered_rows<R>(self, rows: ReadSignal<Vec<R>>) -> impl Iterator<Item = RowData<C, R>>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let indices = self.with_row_indices(rows, |indices| indices.to_vec());
+        self.row_data(rows, indices)
+    }
+
+    /// Returns the number of rows that pass the filters, across all pages.
+    pub fn filtered_row_count<R>(self, rows: ReadSignal<Vec<R>>) -> usize
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        self.with_row_indices(rows, |indices| indices.len())
+    }
+
+    /// Returns the rows of the current page interleaved with the headers of their groups.
+    ///
+    /// Without group-by columns, this yields the same rows as [`rows`](Self::rows). With them,
+    /// pages are made of the visible items: group headers and the rows of expanded groups, so
+    /// collapsing a group pulls the following items onto the page. The headers of a group
+    /// continued from the previous page are repeated on top without counting towards the page.
+    pub fn grouped_rows<R>(self, rows: ReadSignal<Vec<R>>) -> impl Iterator<Item = TableItem<C, R>>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let pagination = *self.data.pagination.read();
+        let group_by = self.data.group_by.read().clone();
+        let collapsed = self.data.collapsed_groups.read();
+        let items = self.with_filtered_rows(rows, |filtered| {
+            if group_by.is_empty() {
+                let page = &filtered.indices[pagination.range(filtered.indices.len())];
+                page.iter().map(|&i| GroupedItem::Row(i)).collect()
+            } else {
+                let visible = filtered.groups.visible(&collapsed);
+                let page = &visible[pagination.range(visible.len())];
+                filtered.groups.items(&filtered.indices, page)
+            }
+        });
+        drop(collapsed);
+        items.into_iter().map(move |item| match item {
+            GroupedItem::Header { path, count } => TableItem::GroupHeader(GroupHeaderData {
+                context: self,
+                column_index: group_by[path.len() - 1],
+                path,
+                count,
+                _phantom: PhantomData,
+            }),
+            GroupedItem::Row(index) => TableItem::Row(RowData {
+                context: self,
+                rows,
+                index,
+                _phantom: PhantomData,
+            }),
+        })
+    }
+
+    /// Returns the number of items paginated over: the filtered rows, or with group-by columns,
+    /// the visible items of [`grouped_rows`](Self::grouped_rows).
+    pub(crate) fn paginated_count<R>(self, rows: ReadSignal<Vec<R>>) -> usize
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let grouped = !self.data.group_by.read().is_empty();
+        let collapsed = self.data.collapsed_groups.read();
+        self.with_filtered_rows(rows, |filtered| {
+            if grouped {
+                filtered.groups.visible(&collapsed).len()
+            } else {
+                filtered.indices.len()
+            }
+        })
+    }
+
+    /// Applies a selection gesture on the row with the given key.
+    ///
+    /// `Range` selects the rows between the anchor and this row in the filtered and sorted order,
+    /// across all pages.
+    pub fn select_row<R>(self, rows: ReadSignal<Vec<R>>, key: &str, gesture: SelectionGesture)
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let order = match gesture {
+            SelectionGesture::Range => {
+                let rows_data = rows.read();
+                self.with_row_indices(rows, |indices| {
+                    indices.iter().map(|&i| rows_data[i].key().into()).collect()
+                })
+            }
+            SelectionGesture::Replace | SelectionGesture::Toggle => Vec::new(),
+        };
+        self.data.select_row(key, gesture, &order);
+    }
+
+    pub(crate) fn with_row_indices<R, T>(
+        self,
+        rows: ReadSignal<Vec<R>>,
+        f: impl FnOnce(&[usize]) -> T,
+    ) -> T
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        self.with_filtered_rows(rows, |filtered| f(&filtered.indices))
+    }
+
+    /// Calls `f` with the memoized output of the filter/sort pipeline.
+    pub(crate) fn with_filtered_rows<R, T>(
+        self,
+        rows: ReadSignal<Vec<R>>,
+        f: impl FnOnce(&FilteredRows) -> T,
+    ) -> T
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        rows_cache::with_cached_rows(
+            self.rows_cache,
+            rows,
+            move |rows_data| self.filter_and_sort(rows_data),
+            f,
+        )
+    }
+
+    pub(crate) fn row_data<R>(
+        self,
+        rows: ReadSignal<Vec<R>>,
+        indices: Vec<usize>,
+    ) -> impl Iterator<Item = RowData<C, R>>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        indices.into_iter().map(move |i| RowData {
+            context: self,
+            rows,
+            index: i,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns whether the last call to [`rows`](Self::rows) reused the memoized result
+    /// instead of re-running the filters and sorts.
+    ///
+    /// The result is recomputed when the rows, the sort records or a signal read by a column
+    /// filter or comparator change, or when the table is given a different rows signal.
+    pub fn rows_cache_reused(&self) -> bool {
+        self.rows_cache.read().reused()
+    }
+
+    fn filter_and_sort<R>(&self, rows_data: &[R]) -> FilteredRows
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let filtered_indices = self.filter_indices(rows_data);
+        self.sort_indices(rows_data, filtered_indices)
+    }
+
+    /// Returns the indices of the rows that pass the filters and the search, in original order.
+    fn filter_indices<R>(&self, rows_data: &[R]) -> Vec<usize>
+    where
+        C: Columns<R>,
+        R: Row,
+    {
         let columns = self.columns.read();
 
         // Step 1: Apply filter - collect indices of rows that pass the filter
-        let mut filtered_indices: Vec<usize> = (0..rows_data.len())
-            .filter(|&i| columns.filter(&rows_data[i]))
-            .collect();
+        let search = self.data.search.read();
+        let search_texts = columns.search_text();
+        // Only visible columns are searched, and only while there is a query
+        let searched_columns = if search.is_empty() {
+            Vec::new()
+        } else {
+            self.data.get_column_order()
+        };
+        let filter_expr = self.data.filter_expr.read();
+        let filters = columns.filters();
+        let unmentioned: Vec<usize> = match filter_expr.as_ref() {
+            Some(expr) => (0..filters.len()).filter(|&c| !expr.mentions(c)).collect(),
+            None => Vec::new(),
+        };
+        let passes_filters = |row: &R| match filter_expr.as_ref() {
+            None => columns.filter(row),
+            Some(expr) => {
+                // Unknown columns have no filter to apply
+                let active = |column: usize| filters.get(column)?.as_ref().map(|f| f(row));
+                expr.evaluate(&active).unwrap_or(true)
+                    && unmentioned
+                        .iter()
+                        .all(|&column| filters[column].as_ref().is_none_or(|f| f(row)))
+            }
+        };
+        (0..rows_data.len())
+            .filter(|&i| passes_filters(&rows_data[i]))
+            .filter(|&i| {
+                search.is_empty()
+                    || searched_columns.iter().any(|&column| {
+                        search_texts[column](&rows_data[i])
+                            .is_some_and(|text| search.matches(&text))
+                    })
+            })
+            .collect()
+    }
+
+    /// Orders the given row indices by the sorts, then makes the rows of each group contiguous.
+    pub(crate) fn sort_indices<R>(
+        &self,
+        rows_data: &[R],
+        mut filtered_indices: Vec<usize>,
+    ) -> FilteredRows
+    where
+        C: Columns<R>,
+        R: Row,
+    {
+        let columns = self.columns.read();
 
         // Step 2: Apply sort if any sort records exist
         let sort_records = self.data.sorts.read();
@@ -239,17 +718,52 @@ impl<C> TableContext<C> {
             });
         }
 
-        // Step 3: Return iterator over sorted and filtered indices
-        filtered_indices.into_iter().map(move |i| RowData {
-            context: self,
-            rows,
-            index: i,
-            _phantom: PhantomData,
-        })
+        // Step 3: Make the rows of each group contiguous, keeping the sorted order within groups
+        let group_by = self.data.group_by.read();
+        if group_by.is_empty() {
+            return FilteredRows {
+                indices: filtered_indices,
+                groups: Groups::default(),
+            };
+        }
+        let group_keys = columns.group_key();
+        let (indices, groups) = grouping::group_order(&filtered_indices, |i| {
+            group_by
+                .iter()
+                .map(|&column| group_keys[column](&rows_data[i]))
+                .collect()
+        });
+        FilteredRows { indices, groups }
     }
 }
 
 impl TableContextData {
+    /// Creates the state of a table with `columns` unsized columns named "Column 0" onwards.
+    #[cfg(test)]
+    pub(crate) fn use_for_test(columns: usize) -> Self {
+        Self {
+            sorts: use_signal(Vec::new),
+            column_names: use_signal(|| (0..columns).map(|i| format!("Column {i}")).collect()),
+            column_order: use_signal(|| ColumnOrder::new(columns)),
+            column_widths: use_signal(|| {
+                ColumnWidths::new(
+                    vec![None; columns],
+                    vec![None; columns],
+                    vec![None; columns],
+                )
+            }),
+            filter_expr: use_signal(|| None),
+            search: use_signal(Search::default),
+            pagination: use_signal(Pagination::default),
+            selection: use_signal(Selection::default),
+            expanded: use_signal(HashSet::new),
+            group_by: use_signal(Vec::new),
+            collapsed_groups: use_signal(HashSet::new),
+            header_elements: use_hook(|| CopyValue::new(HashMap::new())),
+            resizing: use_signal(|| false),
+        }
+    }
+
     pub fn column_context(&self, column: usize) -> ColumnContext {
         ColumnContext {
             table_context: *self,
@@ -265,11 +779,17 @@ impl TableContextData {
         self.column_names.read().len()
     }
 
+    pub fn num_visible_columns(&self) -> usize {
+        self.column_order.read().get_order().len()
+    }
+
     pub fn get_column_name(&self, index: usize) -> String {
         self.column_names.read()[index].clone()
     }
 
     pub fn request_sort(&self, column: usize, sort: SortGesture) {
+        // A new sort order makes the current page meaningless
+        self.reset_page();
         match sort {
             SortGesture::Cancel => {
                 let mut signal = self.sorts;
@@ -299,6 +819,79 @@ impl TableContextData {
         }
     }
 
+    pub fn snapshot(&self) -> TableState {
+        let column_names = self.column_names.read();
+        TableState::capture(
+            &column_names,
+            &self.sorts.read(),
+            &self.column_order.read(),
+            &self.column_widths.read(),
+        )
+    }
+
+    /// Switches to a new set of columns, carrying the sorts, order, visibility, pins, widths
+    /// and group-by columns over to the columns with the same names.
+    ///
+    /// The filter expression refers to columns by index, so it is cleared.
+    pub(crate) fn set_columns(&self, column_names: Vec<String>, widths: ColumnWidths) {
+        let state = TableState::capture(
+            &self.column_names.peek(),
+            &self.sorts.peek(),
+            &self.column_order.peek(),
+            &self.column_widths.peek(),
+        );
+        let group_by: Vec<String> = self
+            .group_by
+            .peek()
+            .iter()
+            .map(|&col| self.column_names.peek()[col].clone())
+            .collect();
+
+        let mut signal = self.column_order;
+        signal.set(ColumnOrder::new(column_names.len()));
+        let mut signal = self.column_widths;
+        signal.set(widths);
+        let mut signal = self.column_names;
+        signal.set(column_names);
+        let mut elements = self.header_elements;
+        elements.write().clear();
+        if self.filter_expr.peek().is_some() {
+            let mut signal = self.filter_expr;
+            signal.set(None);
+        }
+        self.restore(&state);
+        if !group_by.is_empty() {
+            let column_names = self.column_names.peek();
+            let group_by = group_by
+                .iter()
+                .filter_map(|name| column_names.iter().position(|n| n == name))
+                .collect();
+            drop(column_names);
+            self.set_group_by(group_by);
+        }
+    }
+
+    pub fn restore(&self, state: &TableState) {
+        let column_names = self.column_names.peek();
+        let (sorts, order) = state.resolve(&column_names);
+        let pins = state.resolve_pins(&column_names);
+        let widths = state.resolve_widths(&column_names);
+        drop(column_names);
+        self.reset_page();
+        let mut signal = self.sorts;
+        signal.set(sorts);
+        let mut signal = self.column_order;
+        let mut column_order = signal.write();
+        column_order.set_pins(pins);
+        column_order.set_order(order);
+        let mut signal = self.column_widths;
+        let mut column_widths = signal.write();
+        column_widths.reset();
+        for (col, width) in widths {
+            column_widths.set(col, width);
+        }
+    }
+
     // Column order management methods
 
     pub fn swap_columns(&self, col_a: usize, col_b: usize) {
@@ -331,17 +924,313 @@ impl TableContextData {
         signal.write().move_backward(col);
     }
 
-    pub fn is_column_visible(&self, col: usize) -> bool {
-        self.column_order.read().is_visible(col)
+    pub fn is_column_visible(&self, col: usize) -> bool {
+        self.column_order.read().is_visible(col)
+    }
+
+    pub fn column_position(&self, col: usize) -> Option<usize> {
+        self.column_order.read().position(col)
+    }
+
+    pub fn reset_column_order(&self) {
+        let mut signal = self.column_order;
+        signal.write().reset();
+    }
+
+    pub fn pin_column(&self, col: usize, pin: PinState) {
+        let mut signal = self.column_order;
+        signal.write().set_pin_state(col, pin);
+    }
+
+    pub fn column_pin_state(&self, col: usize) -> PinState {
+        self.column_order.read().pin_state(col)
+    }
+
+    /// Returns the sticky positioning attributes of a pinned column, or nothing if unpinned.
+    ///
+    /// The offset of a column pinned to the left is the total width of the left-pinned columns
+    /// before it, and of one pinned to the right the total width of the right-pinned columns
+    /// after it, so that pinned columns stack up against the edge. If one of those columns has
+    /// no width, the offset is unknown and the column only gets its `data-pinned` attribute.
+    pub(crate) fn pin_attributes(&self, column: usize) -> Vec<Attribute> {
+        let column_order = self.column_order.read();
+        let (edge, pinned) = match column_order.pin_state(column) {
+            PinState::Unpinned => return Vec::new(),
+            PinState::Left => ("left", PinState::Left),
+            PinState::Right => ("right", PinState::Right),
+        };
+        let mut stacked: Vec<usize> = column_order
+            .get_order()
+            .iter()
+            .copied()
+            .filter(|&col| column_order.pin_state(col) == pinned)
+            .collect();
+        if pinned == PinState::Right {
+            stacked.reverse();
+        }
+        let widths = self.column_widths.read();
+        let offset = stacked
+            .iter()
+            .take_while(|&&col| col != column)
+            .try_fold(0.0, |offset, &col| Some(offset + widths.get(col)?));
+        let Some(offset) = offset else {
+            return vec![Attribute::new("data-pinned", edge, None, false)];
+        };
+        vec![
+            Attribute::new("position", "sticky", Some("style"), false),
+            Attribute::new(edge, format!("{offset}px"), Some("style"), false),
+            Attribute::new("z-index", "1", Some("style"), false),
+            Attribute::new("data-pinned", edge, None, false),
+        ]
+    }
+
+    // Column width methods
+
+    pub fn column_width(&self, col: usize) -> Option<f64> {
+        self.column_widths.read().get(col)
+    }
+
+    pub fn set_column_width(&self, col: usize, width: f64) {
+        let mut signal = self.column_widths;
+        signal.write().set(col, width);
+    }
+
+    pub fn reset_column_width(&self, col: usize) {
+        let mut signal = self.column_widths;
+        signal.write().reset_column(col);
+    }
+
+    pub fn reset_column_widths(&self) {
+        let mut signal = self.column_widths;
+        signal.write().reset();
+    }
+
+    pub fn is_resizing(&self) -> bool {
+        (self.resizing)()
+    }
+
+    pub fn set_resizing(&self, resizing: bool) {
+        let mut signal = self.resizing;
+        if *signal.peek() != resizing {
+            signal.set(resizing);
+        }
+    }
+
+    /// Returns the width attribute of a column, or nothing if it has no width.
+    pub(crate) fn width_attributes(&self, column: usize) -> Vec<Attribute> {
+        self.column_width(column)
+            .map(|width| Attribute::new("width", format!("{width}px"), Some("style"), false))
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the attributes added to the headers, cells and footers of a column.
+    pub(crate) fn column_attributes(&self, column: usize) -> Vec<Attribute> {
+        let mut attributes = self.width_attributes(column);
+        attributes.extend(self.pin_attributes(column));
+        attributes
+    }
+
+    // Filter composition methods
+
+    pub fn filter_expr(&self) -> Option<FilterExpr> {
+        self.filter_expr.read().clone()
+    }
+
+    pub fn set_filter_expr(&self, expr: Option<FilterExpr>) {
+        self.reset_page();
+        let mut signal = self.filter_expr;
+        signal.set(expr);
+    }
+
+    // Search methods
+
+    pub fn search(&self) -> Search {
+        self.search.read().clone()
+    }
+
+    pub fn set_search_query(&self, query: String) {
+        // Avoid notifying subscribers (and resetting the page) if the query is unchanged
+        if self.search.peek().query() != query {
+            self.reset_page();
+            let mut signal = self.search;
+            signal.write().set_query(query);
+        }
+    }
+
+    pub fn set_search_options(&self, options: SearchOptions) {
+        if self.search.peek().options() != options {
+            self.reset_page();
+            let mut signal = self.search;
+            signal.write().set_options(options);
+        }
+    }
+
+    // Pagination methods
+
+    pub fn pagination(&self) -> Pagination {
+        *self.pagination.read()
+    }
+
+    pub fn set_page_size(&self, page_size: Option<usize>) {
+        let mut signal = self.pagination;
+        signal.write().set_page_size(page_size);
+    }
+
+    pub fn go_to_page(&self, page: usize, row_count: usize) {
+        let mut signal = self.pagination;
+        signal.write().go_to_page(page, row_count);
+    }
+
+    pub fn next_page(&self, row_count: usize) {
+        let mut signal = self.pagination;
+        signal.write().next_page(row_count);
+    }
+
+    pub fn prev_page(&self, row_count: usize) {
+        let mut signal = self.pagination;
+        signal.write().prev_page(row_count);
+    }
+
+    pub fn reset_page(&self) {
+        // Avoid notifying subscribers when already on the first page
+        if self.pagination.peek().page != 0 {
+            let mut signal = self.pagination;
+            signal.write().reset_page();
+        }
+    }
+
+    // Selection methods
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection.read().mode()
+    }
+
+    pub fn set_selection_mode(&self, mode: SelectionMode) {
+        let mut signal = self.selection;
+        signal.write().set_mode(mode);
+    }
+
+    pub fn is_row_selected(&self, key: &str) -> bool {
+        self.selection.read().is_selected(key)
+    }
+
+    pub fn select_row(&self, key: &str, gesture: SelectionGesture, order: &[String]) {
+        let mut signal = self.selection;
+        signal.write().select(key, gesture, order);
+    }
+
+    pub fn select_rows(&self, keys: &[String]) {
+        let mut signal = self.selection;
+        signal.write().select_all(keys);
+    }
+
+    pub fn invert_rows(&self, keys: &[String]) {
+        let mut signal = self.selection;
+        signal.write().invert(keys);
+    }
+
+    pub fn clear_selection(&self) {
+        let mut signal = self.selection;
+        signal.write().clear();
+    }
+
+    pub fn retain_selection(&self, exists: impl Fn(&str) -> bool) {
+        // Only notify subscribers if a key was actually removed
+        let mut selection = self.selection.peek().clone();
+        if selection.retain(exists) {
+            let mut signal = self.selection;
+            signal.set(selection);
+        }
+    }
+
+    // Row expansion methods
+
+    pub fn is_row_expanded(&self, key: &str) -> bool {
+        self.expanded.read().contains(key)
+    }
+
+    pub fn set_row_expanded(&self, key: &str, expanded: bool) {
+        let mut signal = self.expanded;
+        if expanded {
+            signal.write().insert(key.to_string());
+        } else {
+            signal.write().remove(key);
+        }
+    }
+
+    pub fn toggle_row_expanded(&self, key: &str) {
+        let expanded = self.expanded.peek().contains(key);
+        self.set_row_expanded(key, !expanded);
+    }
+
+    pub fn collapse_all_rows(&self) {
+        let mut signal = self.expanded;
+        signal.write().clear();
+    }
+
+    pub fn retain_expanded(&self, exists: impl Fn(&str) -> bool) {
+        // Only notify subscribers if a key was actually removed
+        if self.expanded.peek().iter().any(|key| !exists(key)) {
+            let mut signal = self.expanded;
+            signal.write().retain(|key| exists(key));
+        }
+    }
+
+    // Row grouping methods
+
+    pub fn group_by(&self) -> Vec<usize> {
+        self.group_by.read().clone()
+    }
+
+    pub fn set_group_by(&self, columns: Vec<usize>) {
+        let num_columns = self.num_columns();
+        let mut group_by: Vec<usize> = Vec::with_capacity(columns.len());
+        for column in columns {
+            if column < num_columns && !group_by.contains(&column) {
+                group_by.push(column);
+            }
+        }
+        // Group rows move around, and the collapsed paths no longer refer to the same groups
+        self.reset_page();
+        self.expand_all_groups();
+        let mut signal = self.group_by;
+        signal.set(group_by);
+    }
+
+    pub fn group_level(&self, column: usize) -> Option<usize> {
+        self.group_by.read().iter().position(|&c| c == column)
+    }
+
+    pub fn toggle_group_by(&self, column: usize) {
+        let mut group_by = self.group_by.peek().clone();
+        if let Some(level) = group_by.iter().position(|&c| c == column) {
+            group_by.remove(level);
+        } else {
+            group_by.push(column);
+        }
+        self.set_group_by(group_by);
+    }
+
+    pub fn is_group_collapsed(&self, path: &[Option<String>]) -> bool {
+        self.collapsed_groups.read().contains(path)
     }
 
-    pub fn column_position(&self, col: usize) -> Option<usize> {
-        self.column_order.read().position(col)
+    pub fn set_group_collapsed(&self, path: &[Option<String>], collapsed: bool) {
+        let mut signal = self.collapsed_groups;
+        if collapsed {
+            signal.write().insert(path.to_vec());
+        } else {
+            signal.write().remove(path);
+        }
     }
 
-    pub fn reset_column_order(&self) {
-        let mut signal = self.column_order;
-        signal.write().reset();
+    pub fn expand_all_groups(&self) {
+        // Avoid notifying subscribers when nothing is collapsed
+        if !self.collapsed_groups.peek().is_empty() {
+            let mut signal = self.collapsed_groups;
+            signal.write().clear();
+        }
     }
 }
 
@@ -478,10 +1367,103 @@ impl ColumnContext {
         self.table_context.column_position(self.column)
     }
 
-    /// Resets all columns to default visibility and order.
+    /// Resets all columns to default visibility and order, and unpins them.
     pub fn reset_order(&self) {
         self.table_context.reset_column_order();
     }
+
+    /// Pins this column to the left edge, after the columns already pinned there.
+    ///
+    /// Pinned columns stay in place while the table scrolls horizontally, and are only moved
+    /// or swapped among the columns pinned to the same edge.
+    pub fn pin_left(&self) {
+        self.table_context.pin_column(self.column, PinState::Left);
+    }
+
+    /// Pins this column to the right edge, before the columns already pinned there.
+    pub fn pin_right(&self) {
+        self.table_context.pin_column(self.column, PinState::Right);
+    }
+
+    /// Unpins this column, moving it next to the region it was pinned to.
+    pub fn unpin(&self) {
+        self.table_context
+            .pin_column(self.column, PinState::Unpinned);
+    }
+
+    /// Returns where this column is pinned.
+    pub fn pin_state(&self) -> PinState {
+        self.table_context.column_pin_state(self.column)
+    }
+
+    // Column width delegate methods
+
+    /// Returns the width of this column in pixels, or `None` if the browser sizes it.
+    pub fn width(&self) -> Option<f64> {
+        self.table_context.column_width(self.column)
+    }
+
+    /// Sets the width of this column in pixels, clamped between its
+    /// [`min_width`](crate::TableColumn::min_width) and [`max_width`](crate::TableColumn::max_width).
+    ///
+    /// See [`ColumnResizeHandle`](crate::ColumnResizeHandle) to resize columns by dragging.
+    pub fn set_width(&self, width: f64) {
+        self.table_context.set_column_width(self.column, width);
+    }
+
+    /// Returns the width of this column, measuring its header if the browser sizes it.
+    ///
+    /// Falls back to `None` if the header was not rendered with the attributes passed to
+    /// [`render_header`](crate::TableColumn::render_header), or the renderer can't measure it.
+    pub(crate) async fn measure_width(&self) -> Option<f64> {
+        if let Some(width) = self.width() {
+            return Some(width);
+        }
+        let element = self
+            .table_context
+            .header_elements
+            .read()
+            .get(&self.column)
+            .cloned()?;
+        let rect = element.get_client_rect().await.ok()?;
+        Some(rect.width())
+    }
+
+    /// Marks the start of a resize of this column, e.g. when a drag starts.
+    ///
+    /// Until [`end_resize`](Self::end_resize), the widths set are not saved by
+    /// [`use_tabular_with_storage`](crate::use_tabular_with_storage), which saves the final one.
+    pub fn begin_resize(&self) {
+        self.table_context.set_resizing(true);
+    }
+
+    /// Marks the end of a resize started with [`begin_resize`](Self::begin_resize).
+    pub fn end_resize(&self) {
+        self.table_context.set_resizing(false);
+    }
+
+    /// Resets this column to its [`default_width`](crate::TableColumn::default_width).
+    pub fn reset_width(&self) {
+        self.table_context.reset_column_width(self.column);
+    }
+
+    /// Resets all columns to their default widths.
+    pub fn reset_widths(&self) {
+        self.table_context.reset_column_widths();
+    }
+
+    // Row grouping delegate methods
+
+    /// Returns the nesting level of this column among the group-by columns
+    /// (0 = outermost), or `None` if rows are not grouped by this column.
+    pub fn group_level(&self) -> Option<usize> {
+        self.table_context.group_level(self.column)
+    }
+
+    /// Groups rows by this column as the innermost group, or stops grouping by it.
+    pub fn toggle_grouping(&self) {
+        self.table_context.toggle_group_by(self.column);
+    }
 }
 
 /// Data for rendering a single header cell.
@@ -505,11 +1487,60 @@ impl<C: Columns<R>, R: Row> HeaderData<C, R> {
         self.context.data.column_context(self.column_index)
     }
 
-    /// Renders this header with the given attributes.
-    pub fn render(&self, attributes: Vec<Attribute>) -> Element {
+    /// Renders this header with the given attributes, plus the width of the column and the
+    /// sticky positioning attributes if it is pinned.
+    ///
+    /// An `onmounted` listener is added too, so that [`ColumnResizeHandle`](crate::ColumnResizeHandle)
+    /// can measure a column sized by the browser.
+    pub fn render(&self, mut attributes: Vec<Attribute>) -> Element {
+        attributes.extend(self.context.data.column_attributes(self.column_index));
+        let mut header_elements = self.context.data.header_elements;
+        let column = self.column_index;
+        attributes.push(dioxus::html::events::onmounted(
+            move |event: Event<MountedData>| {
+                header_elements.write().insert(column, event.data());
+            },
+        ));
         let binding = self.context.columns.read();
         let headers = binding.headers();
-        headers[self.column_index](&self.context, attributes)
+        headers[self.column_index](self.column_context(), attributes)
+    }
+}
+
+/// Data for rendering a single footer cell.
+///
+/// Returned by iterating over `TableData::footers()`. Primarily used internally.
+#[derive(Copy, Clone, PartialEq)]
+pub struct FooterData<C: Columns<R>, R: Row> {
+    pub(crate) context: TableContext<C>,
+    pub(crate) rows: ReadSignal<Vec<R>>,
+    pub(crate) column_index: usize,
+}
+
+impl<C: Columns<R>, R: Row> FooterData<C, R> {
+    /// Returns the unique key for this footer.
+    pub fn key(&self) -> String {
+        self.context.data.get_column_name(self.column_index)
+    }
+
+    /// Returns the column context for this footer.
+    pub fn column_context(&self) -> ColumnContext {
+        self.context.data.column_context(self.column_index)
+    }
+
+    /// Renders this footer with the given attributes, passing all filtered rows to the column.
+    ///
+    /// Like for headers, the width and sticky positioning attributes of the column are added.
+    pub fn render(&self, mut attributes: Vec<Attribute>) -> Element {
+        attributes.extend(self.context.data.column_attributes(self.column_index));
+        let indices = self
+            .context
+            .with_row_indices(self.rows, |indices| indices.to_vec());
+        let rows = self.rows.read();
+        let mut filtered = indices.iter().map(|&i| &rows[i]);
+        let binding = self.context.columns.read();
+        let footers = binding.footers();
+        footers[self.column_index](self.column_context(), &mut filtered, attributes)
     }
 }
 
@@ -537,6 +1568,239 @@ impl<C: Columns<R>, R: Row> TableData<C, R> {
     pub fn rows(&self) -> impl Iterator<Item = RowData<C, R>> {
         self.context.rows(self.rows)
     }
+
+    /// Returns an iterator over the rows at positions `range` of [`rows`](Self::rows).
+    ///
+    /// Only the rows inside the range are materialized. See [`VirtualTableBody`](crate::VirtualTableBody).
+    pub fn rows_in_range(&self, range: Range<usize>) -> impl Iterator<Item = RowData<C, R>> {
+        self.context.rows_in_range(self.rows, range)
+    }
+
+    /// Returns an iterator over the footers of the visible columns.
+    ///
+    /// See [`TableFooters`](crate::TableFooters).
+    pub fn footers(&self) -> impl Iterator<Item = FooterData<C, R>> {
+        self.context.footers(self.rows)
+    }
+
+    /// Returns the number of rows yielded by [`rows`](Self::rows), i.e. the rows on the current page.
+    pub fn row_count(&self) -> usize {
+        self.context.row_count(self.rows)
+    }
+
+    /// Returns an iterator over all filtered and sorted rows, ignoring pagination.
+    pub fn filtered_rows(&self) -> impl Iterator<Item = RowData<C, R>> {
+        self.context.filtered_rows(self.rows)
+    }
+
+    /// Returns whether the last call to [`rows`](Self::rows) was served from the memoized
+    /// filter/sort result. See [`TableContext::rows_cache_reused`].
+    pub fn rows_cache_reused(&self) -> bool {
+        self.context.rows_cache_reused()
+    }
+
+    // Filter composition methods
+
+    /// Returns the expression combining the column filters, or `None` if all filters must pass.
+    pub fn filter_expr(&self) -> Option<FilterExpr> {
+        self.context.data.filter_expr()
+    }
+
+    /// Sets how the column filters are combined and goes back to the first page.
+    ///
+    /// `None` restores the default, where a row must pass the filters of all columns.
+    pub fn set_filter_expr(&self, expr: Option<FilterExpr>) {
+        self.context.data.set_filter_expr(expr);
+    }
+
+    // Search methods
+
+    /// Returns the table-wide search query.
+    pub fn search_query(&self) -> String {
+        self.context.data.search().query().to_string()
+    }
+
+    /// Sets the table-wide search query and goes back to the first page.
+    ///
+    /// Rows are kept if the [`search_text`](crate::TableColumn::search_text) of any visible column
+    /// contains the query, in addition to passing the column filters. An empty query disables the search.
+    pub fn set_search_query(&self, query: impl Into<String>) {
+        self.context.data.set_search_query(query.into());
+    }
+
+    /// Returns the options used to match the search query.
+    pub fn search_options(&self) -> SearchOptions {
+        self.context.data.search().options()
+    }
+
+    /// Sets the options used to match the search query and goes back to the first page.
+    pub fn set_search_options(&self, options: SearchOptions) {
+        self.context.data.set_search_options(options);
+    }
+
+    /// Clears the table-wide search query.
+    pub fn clear_search(&self) {
+        self.set_search_query(String::new());
+    }
+
+    // Pagination methods
+
+    /// Returns the number of rows that pass the filters, across all pages.
+    pub fn filtered_row_count(&self) -> usize {
+        self.context.filtered_row_count(self.rows)
+    }
+
+    /// Returns the number of rows per page, or `None` if pagination is disabled.
+    pub fn page_size(&self) -> Option<usize> {
+        self.context.data.pagination().page_size()
+    }
+
+    /// Sets the number of rows per page (`None` disables pagination) and goes back to the first page.
+    pub fn set_page_size(&self, page_size: Option<usize>) {
+        self.context.data.set_page_size(page_size);
+    }
+
+    /// Returns the current page (0-indexed), clamped to the last page.
+    pub fn current_page(&self) -> usize {
+        let count = self.context.paginated_count(self.rows);
+        self.context.data.pagination().current_page(count)
+    }
+
+    /// Returns the total number of pages (at least 1).
+    ///
+    /// With group-by columns, pages are made of the visible items of
+    /// [`grouped_rows`](Self::grouped_rows), group headers included.
+    pub fn total_pages(&self) -> usize {
+        let count = self.context.paginated_count(self.rows);
+        self.context.data.pagination().total_pages(count)
+    }
+
+    /// Returns whether there is a page after the current one.
+    pub fn has_next_page(&self) -> bool {
+        self.current_page() + 1 < self.total_pages()
+    }
+
+    /// Returns whether there is a page before the current one.
+    pub fn has_prev_page(&self) -> bool {
+        self.current_page() > 0
+    }
+
+    /// Moves to a specific page (0-indexed), saturated to the last page.
+    pub fn go_to_page(&self, page: usize) {
+        let count = self.context.paginated_count(self.rows);
+        self.context.data.go_to_page(page, count);
+    }
+
+    /// Moves to the next page. Does nothing on the last page.
+    pub fn next_page(&self) {
+        let count = self.context.paginated_count(self.rows);
+        self.context.data.next_page(count);
+    }
+
+    /// Moves to the previous page. Does nothing on the first page.
+    pub fn prev_page(&self) {
+        let count = self.context.paginated_count(self.rows);
+        self.context.data.prev_page(count);
+    }
+
+    // Selection methods
+
+    /// Returns the selection mode.
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.context.data.selection_mode()
+    }
+
+    /// Sets the selection mode. Switching to `Single` keeps at most the last clicked row.
+    pub fn set_selection_mode(&self, mode: SelectionMode) {
+        self.context.data.set_selection_mode(mode);
+    }
+
+    /// Returns the keys of the selected rows in the order of the rows signal.
+    ///
+    /// Rows hidden by filters or pagination stay selected.
+    pub fn selected_keys(&self) -> Vec<String> {
+        let selection = self.context.data.selection.read();
+        self.rows
+            .read()
+            .iter()
+            .map(|row| row.key().into())
+            .filter(|key: &String| selection.is_selected(key))
+            .collect()
+    }
+
+    /// Returns an iterator over the selected rows that pass the filters, in sorted order.
+    pub fn selected_rows(&self) -> impl Iterator<Item = RowData<C, R>> {
+        self.filtered_rows().filter(|row| row.is_selected())
+    }
+
+    /// Selects all rows yielded by [`rows`](Self::rows). Does nothing in `Single` mode.
+    pub fn select_all_visible(&self) {
+        let keys = self.visible_keys();
+        self.context.data.select_rows(&keys);
+    }
+
+    /// Inverts the selection of the rows yielded by [`rows`](Self::rows). Does nothing in `Single` mode.
+    pub fn invert_selection(&self) {
+        let keys = self.visible_keys();
+        self.context.data.invert_rows(&keys);
+    }
+
+    /// Deselects all rows.
+    pub fn clear_selection(&self) {
+        self.context.data.clear_selection();
+    }
+
+    /// Removes selected and expanded keys that no longer exist in the rows signal.
+    ///
+    /// [`use_tabular`](crate::use_tabular) calls this automatically whenever the rows change.
+    pub fn prune_removed_rows(&self) {
+        let keys: HashSet<String> = self
+            .rows
+            .read()
+            .iter()
+            .map(|row| row.key().into())
+            .collect();
+        self.context.data.retain_selection(|key| keys.contains(key));
+        self.context.data.retain_expanded(|key| keys.contains(key));
+    }
+
+    fn visible_keys(&self) -> Vec<String> {
+        self.rows().map(|row| row.key()).collect()
+    }
+
+    // Row expansion methods
+
+    /// Collapses the detail rows of all rows.
+    pub fn collapse_all(&self) {
+        self.context.data.collapse_all_rows();
+    }
+
+    // Row grouping methods
+
+    /// Returns the rows of the current page interleaved with the headers of their groups.
+    ///
+    /// See [`TableContext::grouped_rows`].
+    pub fn grouped_rows(&self) -> impl Iterator<Item = TableItem<C, R>> {
+        self.context.grouped_rows(self.rows)
+    }
+
+    /// Returns the columns rows are grouped by, from the outermost group inwards.
+    pub fn group_by(&self) -> Vec<usize> {
+        self.context.data.group_by()
+    }
+
+    /// Groups rows by the given columns, from the outermost group inwards.
+    ///
+    /// Unknown and duplicate columns are ignored, and an empty list disables grouping.
+    /// This expands all groups and goes back to the first page.
+    pub fn set_group_by(&self, columns: Vec<usize>) {
+        self.context.data.set_group_by(columns);
+    }
+
+    /// Expands all collapsed groups.
+    pub fn expand_all_groups(&self) {
+        self.context.data.expand_all_groups();
+    }
 }
 
 /// Data for a single cell in the table.
@@ -554,12 +1818,14 @@ impl<C: Columns<R>, R: Row> CellData<C, R> {
         self.row.context.data.get_column_name(self.column_index)
     }
 
-    /// Renders this cell with the given attributes.
-    pub fn render(&self, attributes: Vec<Attribute>) -> Element {
+    /// Renders this cell with the given attributes, plus the width of the column and the
+    /// sticky positioning attributes if it is pinned.
+    pub fn render(&self, mut attributes: Vec<Attribute>) -> Element {
+        attributes.extend(self.row.context.data.column_attributes(self.column_index));
         let binding = self.row.context.columns.read();
         let columns = binding.columns();
         columns[self.column_index](
-            &self.row.context,
+            self.row.context.data.column_context(self.column_index),
             &self.row.rows.read()[self.row.index],
             attributes,
         )
@@ -596,6 +1862,43 @@ impl<C: Columns<R>, R: Row> RowData<C, R> {
         self.rows.read()[self.index].key().into()
     }
 
+    /// Returns whether this row is selected.
+    pub fn is_selected(&self) -> bool {
+        self.context.data.is_row_selected(&self.key())
+    }
+
+    /// Toggles the selection of this row, keeping the rest of the selection.
+    pub fn toggle_selected(&self) {
+        self.select(SelectionGesture::Toggle);
+    }
+
+    /// Applies a selection gesture on this row, e.g. `Range` for Shift-click.
+    pub fn select(&self, gesture: SelectionGesture) {
+        self.context.select_row(self.rows, &self.key(), gesture);
+    }
+
+    /// Returns whether the detail row of this row is shown.
+    ///
+    /// See [`TableDetailRow`](crate::TableDetailRow).
+    pub fn is_expanded(&self) -> bool {
+        self.context.data.is_row_expanded(&self.key())
+    }
+
+    /// Shows or hides the detail row of this row.
+    pub fn set_expanded(&self, expanded: bool) {
+        self.context.data.set_row_expanded(&self.key(), expanded);
+    }
+
+    /// Toggles the detail row of this row.
+    pub fn toggle_expanded(&self) {
+        self.context.data.toggle_row_expanded(&self.key());
+    }
+
+    /// Returns the number of visible columns, e.g. for the `colspan` of a full-width row.
+    pub fn visible_column_count(&self) -> usize {
+        self.context.data.num_visible_columns()
+    }
+
     /// Returns an iterator over the cells in this row.
     pub fn cells(self) -> impl Iterator<Item = CellData<C, R>> {
         self.context.cells(self)
@@ -610,11 +1913,191 @@ impl<C: Columns<R>, R: Row> RowData<C, R> {
     }
 }
 
+/// An item yielded by [`TableData::grouped_rows`]: either a group header or a row.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let users = use_signal(|| vec![User { id: 1 }]);
+/// #     let data = use_tabular((Col,), users.into());
+/// rsx! {
+///     tbody {
+///         for item in data.grouped_rows() {
+///             match item {
+///                 TableItem::GroupHeader(header) => {
+///                     let label = format!("{} ({})", header.value().unwrap_or("(none)"), header.count());
+///                     rsx! {
+///                         tr { key: "{header.key()}",
+///                             td {
+///                                 colspan: "{header.visible_column_count()}",
+///                                 onclick: move |_| header.toggle_collapsed(),
+///                                 "{label}"
+///                             }
+///                         }
+///                     }
+///                 }
+///                 TableItem::Row(row) => rsx! {
+///                     tr { key: "{row.key()}", TableCells { row } }
+///                 },
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, PartialEq)]
+pub enum TableItem<C: Columns<R>, R: Row> {
+    /// The header of a group, preceding the rows and subgroups of the group.
+    GroupHeader(GroupHeaderData<C, R>),
+    /// A row of the table.
+    Row(RowData<C, R>),
+}
+
+impl<C: Columns<R>, R: Row> TableItem<C, R> {
+    /// Returns a unique key for this item, usable as the `key` of the rendered element.
+    pub fn key(&self) -> String {
+        match self {
+            Self::GroupHeader(header) => header.key(),
+            Self::Row(row) => row.key(),
+        }
+    }
+}
+
+/// Data for the header row of a group.
+///
+/// Returned by iterating over `TableData::grouped_rows()`.
+#[derive(Clone, PartialEq)]
+pub struct GroupHeaderData<C: Columns<R>, R: Row> {
+    pub(crate) context: TableContext<C>,
+    pub(crate) column_index: usize,
+    pub(crate) path: GroupPath,
+    pub(crate) count: usize,
+    _phantom: PhantomData<R>,
+}
+
+impl<C: Columns<R>, R: Row> GroupHeaderData<C, R> {
+    /// Returns the unique key for this group header.
+    pub fn key(&self) -> String {
+        format!("group:{:?}", self.path)
+    }
+
+    /// Returns the group key of this group, as returned by
+    /// [`TableColumn::group_key`](crate::TableColumn::group_key).
+    pub fn value(&self) -> Option<&str> {
+        self.path.last().and_then(|key| key.as_deref())
+    }
+
+    /// Returns the group keys from the outermost group down to this one.
+    pub fn path(&self) -> &[Option<String>] {
+        &self.path
+    }
+
+    /// Returns the nesting depth of this group (0 = outermost).
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    /// Returns the index of the column this group is grouped by.
+    pub fn column_index(&self) -> usize {
+        self.column_index
+    }
+
+    /// Returns the column context of the column this group is grouped by.
+    pub fn column_context(&self) -> ColumnContext {
+        self.context.data.column_context(self.column_index)
+    }
+
+    /// Returns the number of filtered rows in this group, across all pages.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether the rows of this group are hidden.
+    pub fn is_collapsed(&self) -> bool {
+        self.context.data.is_group_collapsed(&self.path)
+    }
+
+    /// Hides or shows the rows of this group.
+    pub fn set_collapsed(&self, collapsed: bool) {
+        self.context.data.set_group_collapsed(&self.path, collapsed);
+    }
+
+    /// Toggles whether the rows of this group are hidden.
+    pub fn toggle_collapsed(&self) {
+        let collapsed = self
+            .context
+            .data
+            .collapsed_groups
+            .peek()
+            .contains(&self.path);
+        self.set_collapsed(!collapsed);
+    }
+
+    /// Returns the number of visible columns, e.g. for the `colspan` of the header cell.
+    pub fn visible_column_count(&self) -> usize {
+        self.context.data.num_visible_columns()
+    }
+}
+
 #[cfg(test)]
 mod tests_sort_request;
 
 #[cfg(test)]
 mod tests_rows_filter_and_sort;
 
+#[cfg(test)]
+mod tests_fixtures;
+
 #[cfg(test)]
 mod tests_column_context;
+
+#[cfg(test)]
+mod tests_pagination;
+
+#[cfg(test)]
+mod tests_selection;
+
+#[cfg(test)]
+mod tests_expansion;
+
+#[cfg(test)]
+mod tests_grouping;
+
+#[cfg(test)]
+mod tests_footers;
+
+#[cfg(test)]
+mod tests_search;
+
+#[cfg(test)]
+mod tests_filter_expr;
+
+#[cfg(test)]
+mod tests_table_state;
+
+#[cfg(test)]
+mod tests_column_pinning;
+
+#[cfg(test)]
+mod tests_column_widths;
+
+#[cfg(test)]
+mod tests_nested_columns;
+
+#[cfg(test)]
+mod tests_dyn_columns;
@@ -0,0 +1,200 @@
+use super::ColumnOrder;
+
+/// Assigns columns to named groups, rendered as an extra header row by
+/// [`TableColumnGroups`](crate::TableColumnGroups).
+///
+/// Columns are identified by [`TableColumn::column_name`](crate::TableColumn::column_name).
+/// Each group header spans the adjacent visible members of the group, so a group is split when
+/// reordering separates its members, and shrinks or disappears when they are hidden.
+///
+/// One `ColumnGroups` describes one header row. For several levels, like years above quarters,
+/// render a row for each level from the outermost inwards.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::ColumnGroups;
+///
+/// let quarters = ColumnGroups::new()
+///     .with_group("Q1", ["q1_revenue", "q1_cost"])
+///     .with_group("Q2", ["q2_revenue", "q2_cost"]);
+/// assert_eq!(quarters.group_of("q2_cost"), Some("Q2"));
+/// assert_eq!(quarters.group_of("region"), None);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ColumnGroups {
+    /// The groups with the names of their columns, in definition order
+    groups: Vec<(String, Vec<String>)>,
+}
+
+/// A cell of a column group header row, spanning adjacent visible columns.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ColumnGroupSpan {
+    /// The label of the group, or `None` for columns outside of any group.
+    pub label: Option<String>,
+    /// The spanned columns (indices into the columns tuple) in display order.
+    pub columns: Vec<usize>,
+}
+
+impl ColumnGroupSpan {
+    /// Returns the number of spanned columns, i.e. the `colspan` of the header.
+    pub fn colspan(&self) -> usize {
+        self.columns.len()
+    }
+}
+
+impl ColumnGroups {
+    /// Creates a new ColumnGroups without any group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a group with the given label and column names. A column listed in several groups
+    /// belongs to the first one.
+    pub fn with_group<S: Into<String>>(
+        mut self,
+        label: impl Into<String>,
+        columns: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let columns = columns.into_iter().map(Into::into).collect();
+        self.groups.push((label.into(), columns));
+        self
+    }
+
+    /// Returns the label of the group of a column, or `None` if it belongs to no group.
+    pub fn group_of(&self, column_name: &str) -> Option<&str> {
+        self.groups
+            .iter()
+            .find(|(_, columns)| columns.iter().any(|name| name == column_name))
+            .map(|(label, _)| label.as_str())
+    }
+
+    /// Splits the visible columns into spans of adjacent columns of the same group.
+    ///
+    /// Adjacent columns outside of any group share one span too. Spans never cross the edge of
+    /// a pinned region, so the header of a pinned group can stick with its columns.
+    pub(crate) fn spans(
+        &self,
+        column_names: &[String],
+        order: &ColumnOrder,
+    ) -> Vec<ColumnGroupSpan> {
+        let mut spans: Vec<ColumnGroupSpan> = Vec::new();
+        for &col in order.get_order() {
+            let label = self.group_of(&column_names[col]).map(str::to_string);
+            match spans.last_mut() {
+                Some(span)
+                    if span.label == label
+                        && span
+                            .columns
+                            .last()
+                            .is_some_and(|&last| order.pin_state(last) == order.pin_state(col)) =>
+                {
+                    span.columns.push(col)
+                }
+                _ => spans.push(ColumnGroupSpan {
+                    label,
+                    columns: vec![col],
+                }),
+            }
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PinState;
+
+    fn names() -> Vec<String> {
+        ["region", "q1_revenue", "q1_cost", "q2_revenue", "q2_cost"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    fn quarters() -> ColumnGroups {
+        ColumnGroups::new()
+            .with_group("Q1", ["q1_revenue", "q1_cost"])
+            .with_group("Q2", ["q2_revenue", "q2_cost"])
+    }
+
+    /// Returns the spans as `(label, columns)` pairs, with an empty label outside of groups
+    fn spans(groups: &ColumnGroups, order: &ColumnOrder) -> Vec<(String, Vec<usize>)> {
+        groups
+            .spans(&names(), order)
+            .into_iter()
+            .map(|span| (span.label.unwrap_or_default(), span.columns))
+            .collect()
+    }
+
+    #[test]
+    fn test_spans() {
+        let order = ColumnOrder::new(5);
+        assert_eq!(
+            spans(&quarters(), &order),
+            [
+                ("".into(), vec![0]),
+                ("Q1".into(), vec![1, 2]),
+                ("Q2".into(), vec![3, 4])
+            ]
+        );
+        assert_eq!(quarters().spans(&names(), &order)[1].colspan(), 2);
+
+        // Adjacent ungrouped columns share a span
+        assert_eq!(
+            spans(&ColumnGroups::new(), &order),
+            [("".into(), vec![0, 1, 2, 3, 4])]
+        );
+    }
+
+    #[test]
+    fn test_reordering_splits_groups() {
+        let mut order = ColumnOrder::new(5);
+        order.move_to(3, 2);
+        assert_eq!(
+            spans(&quarters(), &order),
+            [
+                ("".into(), vec![0]),
+                ("Q1".into(), vec![1]),
+                ("Q2".into(), vec![3]),
+                ("Q1".into(), vec![2]),
+                ("Q2".into(), vec![4])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hidden_columns() {
+        let mut order = ColumnOrder::new(5);
+        order.hide_column(2);
+        order.hide_column(3);
+        order.hide_column(4);
+        assert_eq!(
+            spans(&quarters(), &order),
+            [("".into(), vec![0]), ("Q1".into(), vec![1])]
+        );
+    }
+
+    #[test]
+    fn test_pinning_splits_groups() {
+        let mut order = ColumnOrder::new(5);
+        order.set_pin_state(2, PinState::Right);
+        assert_eq!(
+            spans(&quarters(), &order),
+            [
+                ("".into(), vec![0]),
+                ("Q1".into(), vec![1]),
+                ("Q2".into(), vec![3, 4]),
+                ("Q1".into(), vec![2])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_group_wins() {
+        let groups = quarters().with_group("All", ["q1_cost", "region"]);
+        assert_eq!(groups.group_of("q1_cost"), Some("Q1"));
+        assert_eq!(groups.group_of("region"), Some("All"));
+    }
+}
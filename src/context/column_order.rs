@@ -1,10 +1,29 @@
+use std::ops::Range;
+
+/// Where a column is pinned, so it stays visible while the table scrolls horizontally.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PinState {
+    /// The column scrolls with the table.
+    #[default]
+    Unpinned,
+    /// The column is pinned to the left edge.
+    Left,
+    /// The column is pinned to the right edge.
+    Right,
+}
+
 /// Manages the order and visibility of columns in a table.
+///
+/// The display order is split into three regions: the left-pinned columns, the unpinned
+/// columns and the right-pinned columns. Moving and swapping columns never crosses regions.
 #[derive(Clone, PartialEq, Debug)]
 pub struct ColumnOrder {
     /// The order of visible columns (indices into the column tuple)
     order: Vec<usize>,
     /// Total number of columns available
     total_columns: usize,
+    /// The pin state of each column, hidden ones included
+    pins: Vec<PinState>,
 }
 
 impl ColumnOrder {
@@ -13,6 +32,7 @@ impl ColumnOrder {
         Self {
             order: (0..total_columns).collect(),
             total_columns,
+            pins: vec![PinState::Unpinned; total_columns],
         }
     }
 
@@ -37,8 +57,10 @@ impl ColumnOrder {
         let pos_a = self.order.iter().position(|&c| c == col_a);
         let pos_b = self.order.iter().position(|&c| c == col_b);
 
-        // Only swap if both are visible
-        if let (Some(pos_a), Some(pos_b)) = (pos_a, pos_b) {
+        // Only swap if both are visible and in the same region
+        if let (Some(pos_a), Some(pos_b)) = (pos_a, pos_b)
+            && self.pin_state(col_a) == self.pin_state(col_b)
+        {
             self.order.swap(pos_a, pos_b);
         }
     }
@@ -54,8 +76,8 @@ impl ColumnOrder {
     }
 
     /// Shows a column by inserting it into the display order.
-    /// If at_index is None, appends to the end.
-    /// If at_index is Some(idx), inserts at that position (saturated to valid range).
+    /// If at_index is None, appends to the end of the column's region.
+    /// If at_index is Some(idx), inserts at that position (saturated to the column's region).
     /// If the column is already visible or out of bounds, this is a no-op.
     pub fn show_column(&mut self, col: usize, at_index: Option<usize>) {
         // Saturate to valid column index
@@ -66,18 +88,14 @@ impl ColumnOrder {
             return;
         }
 
-        // Insert at specified position or append
-        match at_index {
-            None => self.order.push(col),
-            Some(idx) => {
-                let insert_pos = idx.min(self.order.len());
-                self.order.insert(insert_pos, col);
-            }
-        }
+        // Insert at specified position or append, within the column's region
+        let region = self.region(self.pin_state(col));
+        let insert_pos = at_index.map_or(region.end, |idx| idx.clamp(region.start, region.end));
+        self.order.insert(insert_pos, col);
     }
 
     /// Moves a column to a specific position in the display order (0-indexed).
-    /// The position is saturated to the valid range.
+    /// The position is saturated to the column's region.
     /// If the column is hidden or out of bounds, this is a no-op.
     pub fn move_to(&mut self, col: usize, new_index: usize) {
         // Saturate to valid column index
@@ -89,29 +107,32 @@ impl ColumnOrder {
             self.order.remove(current_pos);
 
             // Insert at new position (saturated)
-            let insert_pos = new_index.min(self.order.len());
+            let region = self.region(self.pin_state(col));
+            let insert_pos = new_index.clamp(region.start, region.end);
             self.order.insert(insert_pos, col);
         }
     }
 
     /// Moves a column one position forward (towards index 0) in the display order.
-    /// If the column is already first or hidden, this is a no-op.
+    /// If the column is already first in its region or hidden, this is a no-op.
     pub fn move_forward(&mut self, col: usize) {
         // Saturate to valid column index
         let col = col.min(self.total_columns.saturating_sub(1));
 
-        if let Some(pos) = self.order.iter().position(|&c| c == col) && pos > 0 {
+        let region = self.region(self.pin_state(col));
+        if let Some(pos) = self.order.iter().position(|&c| c == col) && pos > region.start {
             self.order.swap(pos, pos - 1);
         }
     }
 
     /// Moves a column one position backward (towards the end) in the display order.
-    /// If the column is already last or hidden, this is a no-op.
+    /// If the column is already last in its region or hidden, this is a no-op.
     pub fn move_backward(&mut self, col: usize) {
         // Saturate to valid column index
         let col = col.min(self.total_columns.saturating_sub(1));
 
-        if let Some(pos) = self.order.iter().position(|&c| c == col) && pos < self.order.len() - 1 {
+        let region = self.region(self.pin_state(col));
+        if let Some(pos) = self.order.iter().position(|&c| c == col) && pos + 1 < region.end {
             self.order.swap(pos, pos + 1);
         }
     }
@@ -130,9 +151,88 @@ impl ColumnOrder {
         self.order.iter().position(|&c| c == col)
     }
 
-    /// Resets the column order to the default state (all columns visible in natural order)
+    /// Resets the column order to the default state (all columns visible in natural order
+    /// and unpinned)
     pub fn reset(&mut self) {
         self.order = (0..self.total_columns).collect();
+        self.pins = vec![PinState::Unpinned; self.total_columns];
+    }
+
+    /// Replaces the display order with the given visible columns; all other columns are hidden.
+    /// Out of bounds and duplicate columns are skipped. Pinned columns are moved to their
+    /// region, keeping their relative order.
+    pub fn set_order(&mut self, order: impl IntoIterator<Item = usize>) {
+        self.order.clear();
+        for col in order {
+            if col < self.total_columns && !self.order.contains(&col) {
+                self.order.push(col);
+            }
+        }
+        self.partition();
+    }
+
+    /// Returns where a column is pinned. Out of bounds columns are unpinned.
+    pub fn pin_state(&self, col: usize) -> PinState {
+        self.pins.get(col).copied().unwrap_or_default()
+    }
+
+    /// Pins or unpins a column, hidden or not.
+    ///
+    /// A visible column pinned to the left moves to the end of the left-pinned columns, and one
+    /// pinned to the right to the start of the right-pinned columns, i.e. next to the unpinned
+    /// columns. An unpinned column moves to the edge of the unpinned columns it came from.
+    /// If the column is out of bounds, this is a no-op.
+    pub fn set_pin_state(&mut self, col: usize, pin: PinState) {
+        if col >= self.total_columns {
+            return;
+        }
+        let old = std::mem::replace(&mut self.pins[col], pin);
+        if old == pin {
+            return;
+        }
+        if let Some(pos) = self.order.iter().position(|&c| c == col) {
+            self.order.remove(pos);
+            let insert_pos = match (old, pin) {
+                (PinState::Right, PinState::Unpinned) | (_, PinState::Right) => {
+                    self.region(PinState::Unpinned).end
+                }
+                (_, PinState::Left | PinState::Unpinned) => self.region(PinState::Left).end,
+            };
+            self.order.insert(insert_pos, col);
+        }
+    }
+
+    /// Replaces the pin states of all columns. Out of bounds columns are skipped.
+    pub fn set_pins(&mut self, pins: impl IntoIterator<Item = (usize, PinState)>) {
+        self.pins = vec![PinState::Unpinned; self.total_columns];
+        for (col, pin) in pins {
+            if col < self.total_columns {
+                self.pins[col] = pin;
+            }
+        }
+        self.partition();
+    }
+
+    /// Returns the range of display positions of a region
+    fn region(&self, pin: PinState) -> Range<usize> {
+        let count = |pin| self.order.iter().filter(|&&c| self.pins[c] == pin).count();
+        let left = count(PinState::Left);
+        let right_start = self.order.len() - count(PinState::Right);
+        match pin {
+            PinState::Left => 0..left,
+            PinState::Unpinned => left..right_start,
+            PinState::Right => right_start..self.order.len(),
+        }
+    }
+
+    /// Moves the pinned columns to their regions, keeping the relative order within regions
+    fn partition(&mut self) {
+        let pins = &self.pins;
+        self.order.sort_by_key(|&c| match pins[c] {
+            PinState::Left => 0,
+            PinState::Unpinned => 1,
+            PinState::Right => 2,
+        });
     }
 }
 
@@ -237,4 +337,81 @@ mod tests {
         assert_eq!(order.get_order(), &[0, 1, 2]);
         assert!(order.is_visible(1));
     }
+
+    #[test]
+    fn test_set_order() {
+        let mut order = ColumnOrder::new(4);
+        order.set_order([3, 1, 100, 3]); // Out of bounds and duplicates are skipped
+        assert_eq!(order.get_order(), &[3, 1]);
+        assert!(!order.is_visible(0));
+    }
+
+    #[test]
+    fn test_pin() {
+        let mut order = ColumnOrder::new(5);
+        order.set_pin_state(3, PinState::Left);
+        assert_eq!(order.get_order(), &[3, 0, 1, 2, 4]);
+        order.set_pin_state(1, PinState::Left);
+        assert_eq!(order.get_order(), &[3, 1, 0, 2, 4]);
+        order.set_pin_state(0, PinState::Right);
+        assert_eq!(order.get_order(), &[3, 1, 2, 4, 0]);
+        assert_eq!(order.pin_state(0), PinState::Right);
+
+        // Unpinned columns go back next to the region they left
+        order.set_pin_state(3, PinState::Unpinned);
+        assert_eq!(order.get_order(), &[1, 3, 2, 4, 0]);
+        order.set_pin_state(0, PinState::Unpinned);
+        assert_eq!(order.get_order(), &[1, 3, 2, 4, 0]);
+        assert_eq!(order.pin_state(0), PinState::Unpinned);
+    }
+
+    #[test]
+    fn test_moves_stay_within_region() {
+        let mut order = ColumnOrder::new(5);
+        order.set_pin_state(0, PinState::Left);
+        order.set_pin_state(4, PinState::Right);
+
+        order.move_to(2, 0);
+        assert_eq!(order.get_order(), &[0, 2, 1, 3, 4]);
+        order.move_to(2, 100);
+        assert_eq!(order.get_order(), &[0, 1, 3, 2, 4]);
+        order.move_backward(2);
+        assert_eq!(order.get_order(), &[0, 1, 3, 2, 4]);
+        order.move_forward(1);
+        assert_eq!(order.get_order(), &[0, 1, 3, 2, 4]);
+        order.move_to(4, 0);
+        assert_eq!(order.get_order(), &[0, 1, 3, 2, 4]);
+
+        // Swapping across regions is a no-op
+        order.swap(0, 1);
+        assert_eq!(order.get_order(), &[0, 1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn test_show_pinned_column() {
+        let mut order = ColumnOrder::new(4);
+        order.set_pin_state(3, PinState::Right);
+        order.hide_column(1);
+        order.show_column(1, None);
+        assert_eq!(order.get_order(), &[0, 2, 1, 3]);
+
+        // Hidden columns keep their pin state
+        order.hide_column(3);
+        order.show_column(3, Some(0));
+        assert_eq!(order.get_order(), &[0, 2, 1, 3]);
+        assert_eq!(order.pin_state(3), PinState::Right);
+    }
+
+    #[test]
+    fn test_set_order_partitions_pins() {
+        let mut order = ColumnOrder::new(4);
+        order.set_pins([(2, PinState::Left), (0, PinState::Right)]);
+        assert_eq!(order.get_order(), &[2, 1, 3, 0]);
+        order.set_order([0, 1, 2, 3]);
+        assert_eq!(order.get_order(), &[2, 1, 3, 0]);
+
+        order.reset();
+        assert_eq!(order.get_order(), &[0, 1, 2, 3]);
+        assert_eq!(order.pin_state(2), PinState::Unpinned);
+    }
 }
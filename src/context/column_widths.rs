@@ -0,0 +1,133 @@
+/// Manages the widths of columns in a table.
+///
+/// Widths are in pixels and kept within the minimum and maximum width of each column.
+/// A column without a width is sized by the browser.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct ColumnWidths {
+    /// The current width of each column
+    widths: Vec<Option<f64>>,
+    /// The width of each column before it was resized
+    defaults: Vec<Option<f64>>,
+    /// The smallest width of each column
+    min: Vec<Option<f64>>,
+    /// The largest width of each column
+    max: Vec<Option<f64>>,
+}
+
+impl ColumnWidths {
+    /// Creates a new ColumnWidths with the given default widths, clamped to their limits
+    pub fn new(defaults: Vec<Option<f64>>, min: Vec<Option<f64>>, max: Vec<Option<f64>>) -> Self {
+        let mut widths = Self {
+            widths: Vec::new(),
+            defaults,
+            min,
+            max,
+        };
+        widths.defaults = (0..widths.defaults.len())
+            .map(|col| widths.defaults[col].map(|width| widths.clamp(col, width)))
+            .collect();
+        widths.widths = widths.defaults.clone();
+        widths
+    }
+
+    /// Returns the width of a column, or `None` if it has no width or is out of bounds
+    pub fn get(&self, col: usize) -> Option<f64> {
+        self.widths.get(col).copied().flatten()
+    }
+
+    /// Sets the width of a column, clamped to its limits.
+    /// If the column is out of bounds or the width is NaN, this is a no-op.
+    pub fn set(&mut self, col: usize, width: f64) {
+        if col < self.widths.len() && !width.is_nan() {
+            self.widths[col] = Some(self.clamp(col, width));
+        }
+    }
+
+    /// Returns whether a column has its default width
+    pub fn is_default(&self, col: usize) -> bool {
+        self.widths.get(col) == self.defaults.get(col)
+    }
+
+    /// Resets a column to its default width
+    pub fn reset_column(&mut self, col: usize) {
+        if col < self.widths.len() {
+            self.widths[col] = self.defaults[col];
+        }
+    }
+
+    /// Resets all columns to their default widths
+    pub fn reset(&mut self) {
+        self.widths = self.defaults.clone();
+    }
+
+    /// Clamps a width to the limits of a column, the maximum winning over the minimum
+    fn clamp(&self, col: usize, width: f64) -> f64 {
+        let min = self.min.get(col).copied().flatten().unwrap_or(0.0);
+        let max = self
+            .max
+            .get(col)
+            .copied()
+            .flatten()
+            .unwrap_or(f64::INFINITY);
+        width.max(min).min(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths() -> ColumnWidths {
+        ColumnWidths::new(
+            vec![Some(100.0), None, Some(10.0)],
+            vec![Some(50.0), None, Some(20.0)],
+            vec![Some(200.0), None, None],
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let widths = widths();
+        assert_eq!(widths.get(0), Some(100.0));
+        assert_eq!(widths.get(1), None);
+        // Defaults are clamped too
+        assert_eq!(widths.get(2), Some(20.0));
+        assert_eq!(widths.get(3), None);
+        assert!(widths.is_default(2));
+    }
+
+    #[test]
+    fn test_set() {
+        let mut widths = widths();
+        widths.set(0, 150.0);
+        assert_eq!(widths.get(0), Some(150.0));
+        assert!(!widths.is_default(0));
+
+        widths.set(0, 10.0);
+        assert_eq!(widths.get(0), Some(50.0));
+        widths.set(0, 1000.0);
+        assert_eq!(widths.get(0), Some(200.0));
+        widths.set(0, f64::NAN);
+        assert_eq!(widths.get(0), Some(200.0));
+
+        widths.set(1, -5.0);
+        assert_eq!(widths.get(1), Some(0.0));
+        widths.set(100, 5.0);
+        assert_eq!(widths.get(100), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut widths = widths();
+        widths.set(0, 150.0);
+        widths.set(1, 80.0);
+
+        widths.reset_column(1);
+        assert_eq!(widths.get(1), None);
+        assert_eq!(widths.get(0), Some(150.0));
+
+        widths.reset();
+        assert_eq!(widths.get(0), Some(100.0));
+        assert!(widths.is_default(0));
+    }
+}
@@ -0,0 +1,225 @@
+/// A boolean expression combining the filters of individual columns.
+///
+/// By default a table keeps the rows that pass every column filter. Setting a `FilterExpr`
+/// with [`TableData::set_filter_expr`](crate::TableData::set_filter_expr) replaces this with
+/// any combination of AND, OR and NOT. The filters of columns that the expression does not
+/// mention still apply, combined with the expression using AND.
+///
+/// Columns without an active filter (see
+/// [`TableColumn::is_filter_active`](crate::TableColumn::is_filter_active)) are left out of the
+/// expression, so an `Or` over them doesn't match every row and a `Not` doesn't remove every row.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::FilterExpr;
+///
+/// // Keep rows passing the filter of column 2 or of column 1, but not of column 3
+/// let expr = (FilterExpr::column(2) | FilterExpr::column(1)) & !FilterExpr::column(3);
+///
+/// assert_eq!(
+///     expr,
+///     FilterExpr::And(vec![
+///         FilterExpr::Or(vec![FilterExpr::Column(2), FilterExpr::Column(1)]),
+///         FilterExpr::Not(Box::new(FilterExpr::Column(3))),
+///     ])
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum FilterExpr {
+    /// Passes if the row passes the filter of the column at this index.
+    Column(usize),
+    /// Passes if all sub-expressions pass. An empty `And` passes every row.
+    And(Vec<FilterExpr>),
+    /// Passes if any sub-expression passes. An empty `Or` passes no row.
+    Or(Vec<FilterExpr>),
+    /// Passes if the sub-expression does not pass.
+    Not(Box<FilterExpr>),
+}
+
+impl FilterExpr {
+    /// Creates an expression for the filter of the column at `index`
+    pub fn column(index: usize) -> Self {
+        Self::Column(index)
+    }
+
+    /// Creates an expression requiring the filters of all given columns, like the default behavior
+    pub fn all_columns(indices: impl IntoIterator<Item = usize>) -> Self {
+        Self::And(indices.into_iter().map(Self::Column).collect())
+    }
+
+    /// Creates an expression requiring the filter of any of the given columns
+    pub fn any_column(indices: impl IntoIterator<Item = usize>) -> Self {
+        Self::Or(indices.into_iter().map(Self::Column).collect())
+    }
+
+    /// Evaluates this expression, with `column` returning whether a column's filter passes, or
+    /// `None` if the column has no active filter.
+    ///
+    /// Inactive filters are left out: they don't count in an `And` or an `Or`, and negating one
+    /// keeps it inactive. Returns `None` if only inactive filters are involved.
+    /// Sub-expressions are evaluated lazily, from left to right.
+    pub fn evaluate(&self, column: &impl Fn(usize) -> Option<bool>) -> Option<bool> {
+        match self {
+            Self::Column(index) => column(*index),
+            Self::And(exprs) => Self::evaluate_all(exprs, column, false),
+            Self::Or(exprs) => Self::evaluate_all(exprs, column, true),
+            Self::Not(expr) => expr.evaluate(column).map(|passes| !passes),
+        }
+    }
+
+    /// Evaluates `exprs` until one of them results in `short`
+    fn evaluate_all(
+        exprs: &[FilterExpr],
+        column: &impl Fn(usize) -> Option<bool>,
+        short: bool,
+    ) -> Option<bool> {
+        // An empty group is active, so that an empty `Or` still passes no row
+        let mut active = exprs.is_empty();
+        for expr in exprs {
+            match expr.evaluate(column) {
+                Some(passes) if passes == short => return Some(short),
+                Some(_) => active = true,
+                None => {}
+            }
+        }
+        active.then_some(!short)
+    }
+
+    /// Returns whether the column at `index` appears in this expression
+    pub(crate) fn mentions(&self, index: usize) -> bool {
+        match self {
+            Self::Column(column) => *column == index,
+            Self::And(exprs) | Self::Or(exprs) => exprs.iter().any(|expr| expr.mentions(index)),
+            Self::Not(expr) => expr.mentions(index),
+        }
+    }
+}
+
+impl std::ops::BitAnd for FilterExpr {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        match self {
+            Self::And(mut exprs) => {
+                exprs.push(rhs);
+                Self::And(exprs)
+            }
+            lhs => Self::And(vec![lhs, rhs]),
+        }
+    }
+}
+
+impl std::ops::BitOr for FilterExpr {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        match self {
+            Self::Or(mut exprs) => {
+                exprs.push(rhs);
+                Self::Or(exprs)
+            }
+            lhs => Self::Or(vec![lhs, rhs]),
+        }
+    }
+}
+
+impl std::ops::Not for FilterExpr {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(expr) => *expr,
+            expr => Self::Not(Box::new(expr)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Columns 0 and 2 pass, column 1 fails and column 3 has no active filter
+    fn passes(index: usize) -> Option<bool> {
+        (index != 3).then_some(index != 1)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(FilterExpr::column(0).evaluate(&passes), Some(true));
+        assert_eq!(FilterExpr::column(1).evaluate(&passes), Some(false));
+        assert_eq!(
+            FilterExpr::all_columns([0, 1, 2]).evaluate(&passes),
+            Some(false)
+        );
+        assert_eq!(FilterExpr::any_column([1, 2]).evaluate(&passes), Some(true));
+        assert_eq!(
+            FilterExpr::Not(Box::new(FilterExpr::column(0))).evaluate(&passes),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_empty_groups() {
+        assert_eq!(FilterExpr::And(vec![]).evaluate(&passes), Some(true));
+        assert_eq!(FilterExpr::Or(vec![]).evaluate(&passes), Some(false));
+    }
+
+    #[test]
+    fn test_inactive_filters_are_left_out() {
+        assert_eq!(
+            FilterExpr::any_column([1, 3]).evaluate(&passes),
+            Some(false)
+        );
+        assert_eq!(
+            FilterExpr::all_columns([0, 3]).evaluate(&passes),
+            Some(true)
+        );
+        assert_eq!((!FilterExpr::column(3)).evaluate(&passes), None);
+        assert_eq!(FilterExpr::any_column([3, 3]).evaluate(&passes), None);
+        assert_eq!(
+            (FilterExpr::column(1) | !FilterExpr::column(3)).evaluate(&passes),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        // (1 OR 2) AND NOT 1
+        let expr = (FilterExpr::column(1) | FilterExpr::column(2)) & !FilterExpr::column(1);
+        assert_eq!(expr.evaluate(&passes), Some(true));
+
+        // NOT (0 AND 2)
+        let expr = !(FilterExpr::column(0) & FilterExpr::column(2));
+        assert_eq!(expr.evaluate(&passes), Some(false));
+    }
+
+    #[test]
+    fn test_mentions() {
+        let expr = (FilterExpr::column(1) | FilterExpr::column(2)) & !FilterExpr::column(4);
+        assert!(expr.mentions(4));
+        assert!(!expr.mentions(0));
+    }
+
+    #[test]
+    fn test_operators_flatten() {
+        let expr = FilterExpr::column(0) | FilterExpr::column(1) | FilterExpr::column(2);
+        assert_eq!(expr, FilterExpr::any_column([0, 1, 2]));
+
+        let expr = FilterExpr::column(0) & FilterExpr::column(1) & FilterExpr::column(2);
+        assert_eq!(expr, FilterExpr::all_columns([0, 1, 2]));
+
+        assert_eq!(!!FilterExpr::column(0), FilterExpr::column(0));
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let evaluated = std::cell::RefCell::new(Vec::new());
+        let column = |index: usize| {
+            evaluated.borrow_mut().push(index);
+            passes(index)
+        };
+        assert_eq!(FilterExpr::any_column([0, 1]).evaluate(&column), Some(true));
+        assert_eq!(*evaluated.borrow(), [0]);
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+/// The group keys of a row, one per group-by column from the outermost group inwards.
+pub type GroupPath = Vec<Option<String>>;
+
+/// An item of the grouped rows: either a group header or the index of a row.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum GroupedItem {
+    /// The header of the group at `path`, containing `count` filtered rows.
+    Header { path: GroupPath, count: usize },
+    /// The original index of a row.
+    Row(usize),
+}
+
+/// The groups of the filtered rows, computed once along with them.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct Groups {
+    /// The group path of every filtered row, by position in the filtered indices.
+    paths: Vec<GroupPath>,
+    /// The number of filtered rows in every group and subgroup.
+    counts: HashMap<GroupPath, usize>,
+}
+
+/// A visible item of the grouped rows, by position in the filtered indices.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Visible {
+    /// The header of the group of the row at `pos`, at nesting level `depth`.
+    Header { pos: usize, depth: usize },
+    /// The row at `pos`.
+    Row(usize),
+}
+
+/// Reorders `indices` so that rows of the same group are contiguous, and returns the groups
+/// of the reordered rows.
+///
+/// Groups are ordered by the position of their first row in `indices`, and rows keep their
+/// relative order within a group, so a sort applied beforehand orders both the groups and
+/// the rows within them.
+pub(crate) fn group_order(
+    indices: &[usize],
+    path: impl Fn(usize) -> GroupPath,
+) -> (Vec<usize>, Groups) {
+    let paths: Vec<GroupPath> = indices.iter().map(|&i| path(i)).collect();
+
+    // Position of the first row of every group and subgroup
+    let mut first: HashMap<&[Option<String>], usize> = HashMap::new();
+    for (pos, path) in paths.iter().enumerate() {
+        for level in 1..=path.len() {
+            first.entry(&path[..level]).or_insert(pos);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..indices.len()).collect();
+    order.sort_by_cached_key(|&pos| {
+        let path = &paths[pos];
+        let ranks: Vec<usize> = (1..=path.len())
+            .map(|level| first[&path[..level]])
+            .collect();
+        (ranks, pos)
+    });
+
+    let mut counts: HashMap<GroupPath, usize> = HashMap::new();
+    for path in &paths {
+        for level in 1..=path.len() {
+            *counts.entry(path[..level].to_vec()).or_default() += 1;
+        }
+    }
+    let indices = order.iter().map(|&pos| indices[pos]).collect();
+    let paths = order.into_iter().map(|pos| paths[pos].clone()).collect();
+    (indices, Groups { paths, counts })
+}
+
+impl Groups {
+    /// Returns the visible group headers and rows, in display order.
+    ///
+    /// A header is emitted whenever a group starts. Rows and subgroups of collapsed groups are
+    /// skipped, while the header of a collapsed group itself is kept.
+    pub(crate) fn visible(&self, collapsed: &HashSet<GroupPath>) -> Vec<Visible> {
+        let is_hidden = |path: &[Option<String>], depth: usize| {
+            (1..=depth).any(|level| collapsed.contains(&path[..level]))
+        };
+
+        let mut items = Vec::new();
+        let mut previous: &[Option<String>] = &[];
+        for (pos, path) in self.paths.iter().enumerate() {
+            let common = previous
+                .iter()
+                .zip(path)
+                .take_while(|(a, b)| a == b)
+                .count();
+            for depth in common..path.len() {
+                if !is_hidden(path, depth) {
+                    items.push(Visible::Header { pos, depth });
+                }
+            }
+            if !is_hidden(path, path.len()) {
+                items.push(Visible::Row(pos));
+            }
+            previous = path;
+        }
+        items
+    }
+
+    /// Resolves a page of [`visible`](Self::visible) items against the filtered `indices`.
+    ///
+    /// The headers of the groups enclosing the first item are repeated at the top of the page,
+    /// so a group continued from the previous page keeps its header. Header counts cover all
+    /// filtered rows of the group.
+    pub(crate) fn items(&self, indices: &[usize], page: &[Visible]) -> Vec<GroupedItem> {
+        let header = |pos: usize, depth: usize| {
+            let path = self.paths[pos][..=depth].to_vec();
+            let count = self.counts.get(&path).copied().unwrap_or_default();
+            GroupedItem::Header { path, count }
+        };
+        let continued = match page.first() {
+            Some(&Visible::Header { pos, depth }) => (pos, depth),
+            Some(&Visible::Row(pos)) => (pos, self.paths[pos].len()),
+            None => (0, 0),
+        };
+        let (pos, depth) = continued;
+        (0..depth)
+            .map(|depth| header(pos, depth))
+            .chain(page.iter().map(|&item| match item {
+                Visible::Header { pos, depth } => header(pos, depth),
+                Visible::Row(pos) => GroupedItem::Row(indices[pos]),
+            }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Rows are (status, priority) pairs
+    const ROWS: [(&str, &str); 6] = [
+        ("todo", "high"),
+        ("done", "low"),
+        ("todo", "low"),
+        ("done", "low"),
+        ("todo", "high"),
+        ("wip", "high"),
+    ];
+
+    fn status(i: usize) -> GroupPath {
+        vec![Some(ROWS[i].0.to_string())]
+    }
+
+    fn status_priority(i: usize) -> GroupPath {
+        vec![Some(ROWS[i].0.to_string()), Some(ROWS[i].1.to_string())]
+    }
+
+    fn header(keys: &[&str], count: usize) -> GroupedItem {
+        GroupedItem::Header {
+            path: keys.iter().map(|k| Some(k.to_string())).collect(),
+            count,
+        }
+    }
+
+    #[test]
+    fn test_group_order_by_first_appearance() {
+        let (order, _) = group_order(&[0, 1, 2, 3, 4, 5], status);
+        assert_eq!(order, [0, 2, 4, 1, 3, 5]);
+
+        // A different input order (e.g. after sorting) changes the group order
+        let (order, _) = group_order(&[5, 3, 2, 1, 0], status);
+        assert_eq!(order, [5, 3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_group_order_nested() {
+        let (order, _) = group_order(&[0, 1, 2, 3, 4, 5], status_priority);
+        assert_eq!(order, [0, 4, 2, 1, 3, 5]);
+    }
+
+    #[test]
+    fn test_group_order_without_keys() {
+        let (order, _) = group_order(&[2, 0, 1], |_| vec![None]);
+        assert_eq!(order, [2, 0, 1]);
+    }
+
+    #[test]
+    fn test_group_items() {
+        let (order, groups) = group_order(&[0, 1, 2, 3, 4, 5], status);
+        let items = groups.items(&order, &groups.visible(&HashSet::new()));
+        assert_eq!(
+            items,
+            [
+                header(&["todo"], 3),
+                GroupedItem::Row(0),
+                GroupedItem::Row(2),
+                GroupedItem::Row(4),
+                header(&["done"], 2),
+                GroupedItem::Row(1),
+                GroupedItem::Row(3),
+                header(&["wip"], 1),
+                GroupedItem::Row(5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_items_nested_and_collapsed() {
+        let (order, groups) = group_order(&[0, 1, 2, 3, 4, 5], status_priority);
+        let collapsed: HashSet<GroupPath> = [
+            vec![Some("todo".to_string()), Some("high".to_string())],
+            vec![Some("done".to_string())],
+        ]
+        .into_iter()
+        .collect();
+        let items = groups.items(&order, &groups.visible(&collapsed));
+        assert_eq!(
+            items,
+            [
+                header(&["todo"], 3),
+                header(&["todo", "high"], 2),
+                header(&["todo", "low"], 1),
+                GroupedItem::Row(2),
+                header(&["done"], 2),
+                header(&["wip"], 1),
+                header(&["wip", "high"], 1),
+                GroupedItem::Row(5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_items_page_repeats_header() {
+        let (order, groups) = group_order(&[0, 1, 2, 3, 4, 5], status);
+        let visible = groups.visible(&HashSet::new());
+        // The page starts in the middle of the "todo" group
+        let items = groups.items(&order, &visible[2..5]);
+        assert_eq!(
+            items,
+            [
+                header(&["todo"], 3),
+                GroupedItem::Row(2),
+                GroupedItem::Row(4),
+                header(&["done"], 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_items_page_starts_with_subgroup() {
+        let (order, groups) = group_order(&[0, 1, 2, 3, 4, 5], status_priority);
+        let visible = groups.visible(&HashSet::new());
+        let items = groups.items(&order, &visible[4..6]);
+        assert_eq!(
+            items,
+            [
+                header(&["todo"], 3),
+                header(&["todo", "low"], 1),
+                GroupedItem::Row(2),
+            ]
+        );
+    }
+}
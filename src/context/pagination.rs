@@ -0,0 +1,168 @@
+use std::ops::Range;
+
+/// Pagination state of a table.
+///
+/// The current page is stored as requested and clamped against the number of filtered rows
+/// whenever it is read, so a filter that shrinks the result never leaves the table on an empty page.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Pagination {
+    /// Number of rows per page, or `None` to show all rows on a single page.
+    page_size: Option<usize>,
+    /// The requested page (0-indexed).
+    pub(crate) page: usize,
+}
+
+impl Pagination {
+    /// Creates a new Pagination on the first page.
+    /// A page size of 0 is saturated to 1.
+    pub fn new(page_size: Option<usize>) -> Self {
+        Self {
+            page_size: page_size.map(|size| size.max(1)),
+            page: 0,
+        }
+    }
+
+    /// Returns the number of rows per page, or `None` if pagination is disabled
+    pub fn page_size(&self) -> Option<usize> {
+        self.page_size
+    }
+
+    /// Sets the number of rows per page and goes back to the first page.
+    /// A page size of 0 is saturated to 1.
+    pub fn set_page_size(&mut self, page_size: Option<usize>) {
+        *self = Self::new(page_size);
+    }
+
+    /// Returns the total number of pages for the given row count (at least 1)
+    pub fn total_pages(&self, row_count: usize) -> usize {
+        match self.page_size {
+            None => 1,
+            Some(size) => row_count.div_ceil(size).max(1),
+        }
+    }
+
+    /// Returns the current page (0-indexed), clamped to the last page for the given row count
+    pub fn current_page(&self, row_count: usize) -> usize {
+        self.page.min(self.total_pages(row_count) - 1)
+    }
+
+    /// Moves to a specific page (0-indexed), saturated to the last page for the given row count
+    pub fn go_to_page(&mut self, page: usize, row_count: usize) {
+        self.page = page.min(self.total_pages(row_count) - 1);
+    }
+
+    /// Moves to the next page. If already on the last page, this is a no-op.
+    pub fn next_page(&mut self, row_count: usize) {
+        self.go_to_page(self.current_page(row_count) + 1, row_count);
+    }
+
+    /// Moves to the previous page. If already on the first page, this is a no-op.
+    pub fn prev_page(&mut self, row_count: usize) {
+        self.go_to_page(self.current_page(row_count).saturating_sub(1), row_count);
+    }
+
+    /// Goes back to the first page
+    pub fn reset_page(&mut self) {
+        self.page = 0;
+    }
+
+    /// Returns the range of filtered row positions shown on the current page
+    pub fn range(&self, row_count: usize) -> Range<usize> {
+        match self.page_size {
+            None => 0..row_count,
+            Some(size) => {
+                let start = self.current_page(row_count) * size;
+                start..(start + size).min(row_count)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_unpaginated() {
+        let pagination = Pagination::default();
+        assert_eq!(pagination.page_size(), None);
+        assert_eq!(pagination.total_pages(10), 1);
+        assert_eq!(pagination.range(10), 0..10);
+    }
+
+    #[test]
+    fn test_total_pages() {
+        let pagination = Pagination::new(Some(3));
+        assert_eq!(pagination.total_pages(0), 1);
+        assert_eq!(pagination.total_pages(3), 1);
+        assert_eq!(pagination.total_pages(4), 2);
+        assert_eq!(pagination.total_pages(9), 3);
+    }
+
+    #[test]
+    fn test_zero_page_size_saturates() {
+        let pagination = Pagination::new(Some(0));
+        assert_eq!(pagination.page_size(), Some(1));
+    }
+
+    #[test]
+    fn test_range() {
+        let mut pagination = Pagination::new(Some(3));
+        assert_eq!(pagination.range(8), 0..3);
+
+        pagination.go_to_page(2, 8);
+        assert_eq!(pagination.range(8), 6..8);
+    }
+
+    #[test]
+    fn test_go_to_page_saturating() {
+        let mut pagination = Pagination::new(Some(3));
+        pagination.go_to_page(100, 8);
+        assert_eq!(pagination.current_page(8), 2);
+    }
+
+    #[test]
+    fn test_next_prev() {
+        let mut pagination = Pagination::new(Some(3));
+
+        pagination.prev_page(8);
+        assert_eq!(pagination.current_page(8), 0); // No change
+
+        pagination.next_page(8);
+        pagination.next_page(8);
+        assert_eq!(pagination.current_page(8), 2);
+
+        pagination.next_page(8);
+        assert_eq!(pagination.current_page(8), 2); // No change
+
+        pagination.prev_page(8);
+        assert_eq!(pagination.current_page(8), 1);
+    }
+
+    #[test]
+    fn test_clamp_when_rows_shrink() {
+        let mut pagination = Pagination::new(Some(3));
+        pagination.go_to_page(2, 8);
+
+        // A filter leaves only 4 rows: page 2 no longer exists
+        assert_eq!(pagination.current_page(4), 1);
+        assert_eq!(pagination.range(4), 3..4);
+
+        // No rows at all: stay on the first (empty) page
+        assert_eq!(pagination.current_page(0), 0);
+        assert_eq!(pagination.range(0), 0..0);
+    }
+
+    #[test]
+    fn test_set_page_size_resets_page() {
+        let mut pagination = Pagination::new(Some(3));
+        pagination.go_to_page(2, 8);
+
+        pagination.set_page_size(Some(2));
+        assert_eq!(pagination.current_page(8), 0);
+        assert_eq!(pagination.range(8), 0..2);
+
+        pagination.set_page_size(None);
+        assert_eq!(pagination.range(8), 0..8);
+    }
+}
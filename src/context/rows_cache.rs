@@ -0,0 +1,216 @@
+use super::grouping::Groups;
+use dioxus::core::{Runtime, ScopeId};
+use dioxus::prelude::*;
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// The output of the filter/sort pipeline.
+#[derive(PartialEq, Default)]
+pub(crate) struct FilteredRows {
+    /// The filtered and sorted row indices.
+    pub(crate) indices: Vec<usize>,
+    /// The groups of the rows, by position in `indices`. Empty without group-by columns.
+    pub(crate) groups: Groups,
+}
+
+/// Caches the filtered and sorted row indices of a table, along with their groups.
+///
+/// They are held in a [`Memo`] that tracks the rows signal, the sort records and every
+/// signal read by the column filters and comparators, so it is only recomputed when one of them
+/// changes.
+pub(crate) struct RowsCache {
+    /// The scope that owns the table context. Memos are created in this scope so they outlive
+    /// the component that happens to read the rows first.
+    scope: ScopeId,
+    entry: Option<RowsCacheEntry>,
+    /// Set by the memo whenever the filter/sort pipeline runs.
+    computed: Rc<Cell<bool>>,
+    /// Whether the last `rows()` call was served without running the pipeline.
+    reused: bool,
+}
+
+/// The memo of a table, created on the first `rows()` call and kept for the lifetime of the
+/// context. A different rows signal is swapped into `source` rather than getting a new memo.
+struct RowsCacheEntry {
+    /// Address of the `Vec` behind the rows signal the memo was last computed for. Together with
+    /// the source still being readable, it identifies the signal: a live signal never shares its
+    /// storage, and a dropped one can't be read anymore even if its slot is reused.
+    address: usize,
+    /// The `CopyValue<ReadSignal<Vec<R>>>` read by the memo.
+    source: Box<dyn Any>,
+    /// Read by the memo and written when `source` points to a different signal, to recompute it.
+    swapped: Signal<()>,
+    filtered: Memo<FilteredRows>,
+}
+
+impl RowsCache {
+    pub(crate) fn new(scope: ScopeId) -> Self {
+        Self {
+            scope,
+            entry: None,
+            computed: Rc::new(Cell::new(false)),
+            reused: false,
+        }
+    }
+
+    pub(crate) fn reused(&self) -> bool {
+        self.reused
+    }
+}
+
+/// Calls `f` with the memoized filtered rows for `rows`, creating the memo with `compute` on a
+/// cache miss.
+///
+/// A new `ReadSignal` wrapping the same rows signal (e.g. `rows.into()` on every render) reuses
+/// the existing memo.
+pub(crate) fn with_cached_rows<R: 'static, T>(
+    mut cache: CopyValue<RowsCache>,
+    rows: ReadSignal<Vec<R>>,
+    compute: impl Fn(&[R]) -> FilteredRows + 'static,
+    f: impl FnOnce(&FilteredRows) -> T,
+) -> T {
+    // Reading (rather than peeking) keeps the caller subscribed to the rows themselves, so edits
+    // that leave the index list unchanged still re-render it.
+    let address = &*rows.read() as *const Vec<R> as usize;
+
+    let (entry, scope, computed) = {
+        let read = cache.read();
+        let entry = read.entry.as_ref().and_then(|entry| {
+            let source = entry
+                .source
+                .downcast_ref::<CopyValue<ReadSignal<Vec<R>>>>()?;
+            // A signal dropped since may have left its address to the current one
+            let alive = source
+                .try_peek()
+                .is_ok_and(|signal| signal.try_peek().is_ok());
+            Some((
+                *source,
+                entry.swapped,
+                entry.filtered,
+                entry.address == address && alive,
+            ))
+        });
+        (entry, read.scope, read.computed.clone())
+    };
+    computed.set(false);
+
+    let filtered = match entry {
+        Some((mut source, mut swapped, filtered, hit)) => {
+            // Keep reading through the newest wrapper in case the old one gets dropped.
+            source.set(rows);
+            if !hit {
+                // Another rows signal: recompute the memo for it
+                swapped.set(());
+                if let Some(entry) = cache.write().entry.as_mut() {
+                    entry.address = address;
+                }
+            }
+            filtered
+        }
+        None => {
+            // The cache is not borrowed while the memo runs its first computation
+            let (source, swapped, filtered) = Runtime::current().in_scope(scope, || {
+                let source = CopyValue::new(rows);
+                let swapped = Signal::new(());
+                let computed = computed.clone();
+                let filtered = Memo::new(move || {
+                    swapped.read();
+                    computed.set(true);
+                    compute(&source.read().read())
+                });
+                (source, swapped, filtered)
+            });
+            cache.write().entry = Some(RowsCacheEntry {
+                address,
+                source: Box::new(source),
+                swapped,
+                filtered,
+            });
+            filtered
+        }
+    };
+
+    // Reading the memo recomputes it if one of its dependencies changed.
+    let result = f(&filtered.read());
+
+    cache.write().reused = !computed.get();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_suite::test_hook;
+    use dioxus::core::current_scope_id;
+
+    fn indices(cache: CopyValue<RowsCache>, rows: Signal<Vec<u32>>) -> Vec<usize> {
+        let compute = |rows: &[u32]| FilteredRows {
+            indices: (0..rows.len()).collect(),
+            groups: Groups::default(),
+        };
+        with_cached_rows(cache, rows.into(), compute, |filtered| {
+            filtered.indices.to_vec()
+        })
+    }
+
+    #[test]
+    fn test_dropped_signal_at_same_address() {
+        test_hook(
+            || use_hook(|| CopyValue::new(RowsCache::new(current_scope_id()))),
+            |mut cache, proxy| match proxy.generation {
+                0 => {
+                    let old_rows = Signal::new(vec![1, 2, 3]);
+                    assert_eq!(indices(cache, old_rows), [0, 1, 2]);
+                    old_rows.manually_drop();
+
+                    // Pretend the new signal got the storage of the dropped one
+                    let new_rows = Signal::new(vec![4]);
+                    let address = &*new_rows.peek() as *const Vec<u32> as usize;
+                    cache.write().entry.as_mut().unwrap().address = address;
+                    assert_eq!(indices(cache, new_rows), [0]);
+                    assert!(!cache.read().reused());
+
+                    assert_eq!(indices(cache, new_rows), [0]);
+                    assert!(cache.read().reused());
+                }
+                1 => {
+                    // Rerender after the memo changed - no action needed
+                }
+                _ => panic!("Unexpected generation: {}", proxy.generation),
+            },
+            |proxy| assert_eq!(proxy.generation, 1),
+        );
+    }
+
+    #[test]
+    fn test_swapped_signals_share_one_memo() {
+        test_hook(
+            || use_hook(|| CopyValue::new(RowsCache::new(current_scope_id()))),
+            |cache, proxy| match proxy.generation {
+                0 => {
+                    let short = Signal::new(vec![1]);
+                    let long = Signal::new(vec![1, 2, 3]);
+                    assert_eq!(indices(cache, short), [0]);
+                    let memo = cache.read().entry.as_ref().unwrap().filtered.id();
+
+                    for _ in 0..10 {
+                        assert_eq!(indices(cache, long), [0, 1, 2]);
+                        assert!(!cache.read().reused());
+                        assert_eq!(indices(cache, short), [0]);
+                        assert!(!cache.read().reused());
+                    }
+                    assert_eq!(indices(cache, short), [0]);
+                    assert!(cache.read().reused());
+                    // The memo of the first signal was reused for every other one
+                    assert_eq!(cache.read().entry.as_ref().unwrap().filtered.id(), memo);
+                }
+                1 => {
+                    // Rerender after the memo changed - no action needed
+                }
+                _ => panic!("Unexpected generation: {}", proxy.generation),
+            },
+            |proxy| assert_eq!(proxy.generation, 1),
+        );
+    }
+}
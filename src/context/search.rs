@@ -0,0 +1,148 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Options for matching the global search query against cell text.
+///
+/// By default, matching ignores both case and diacritics, so `"zoe"` matches `"Zoë"`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct SearchOptions {
+    /// Distinguish upper and lower case.
+    pub case_sensitive: bool,
+    /// Distinguish letters with and without diacritics (e.g. `é` and `e`).
+    pub diacritic_sensitive: bool,
+}
+
+/// The global search state of a table.
+///
+/// A row matches if the text returned by
+/// [`TableColumn::search_text`](crate::TableColumn::search_text) for any visible column
+/// contains the query. An empty or whitespace-only query matches every row.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Search {
+    query: String,
+    options: SearchOptions,
+    /// The trimmed query, normalized according to `options`
+    normalized: String,
+}
+
+impl Search {
+    /// Creates a new Search with the given query and options
+    pub fn new(query: impl Into<String>, options: SearchOptions) -> Self {
+        let query = query.into();
+        let normalized = normalize(query.trim(), options);
+        Self {
+            query,
+            options,
+            normalized,
+        }
+    }
+
+    /// Returns the query as entered
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the matching options
+    pub fn options(&self) -> SearchOptions {
+        self.options
+    }
+
+    /// Replaces the query, keeping the options
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        *self = Self::new(query, self.options);
+    }
+
+    /// Replaces the options, keeping the query
+    pub fn set_options(&mut self, options: SearchOptions) {
+        *self = Self::new(std::mem::take(&mut self.query), options);
+    }
+
+    /// Checks if the query matches every row
+    pub fn is_empty(&self) -> bool {
+        self.normalized.is_empty()
+    }
+
+    /// Checks if `text` contains the query
+    pub fn matches(&self, text: &str) -> bool {
+        self.is_empty() || normalize(text, self.options).contains(&self.normalized)
+    }
+}
+
+fn normalize(text: &str, options: SearchOptions) -> String {
+    let text = if options.diacritic_sensitive {
+        text.to_string()
+    } else {
+        // Decompose "é" into "e" and a combining accent, then drop the accent
+        text.nfd().filter(|&c| !is_combining_mark(c)).collect()
+    };
+    if options.case_sensitive {
+        text
+    } else {
+        text.to_lowercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        assert!(Search::default().matches("anything"));
+        assert!(Search::new("   ", SearchOptions::default()).matches(""));
+    }
+
+    #[test]
+    fn test_case_insensitive_by_default() {
+        let search = Search::new("ALI", SearchOptions::default());
+        assert!(search.matches("Alice"));
+        assert!(search.matches("Khalil"));
+        assert!(!search.matches("Bob"));
+    }
+
+    #[test]
+    fn test_case_sensitive() {
+        let options = SearchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        let search = Search::new("Ali", options);
+        assert!(search.matches("Alice"));
+        assert!(!search.matches("Khalil"));
+    }
+
+    #[test]
+    fn test_diacritic_insensitive_by_default() {
+        assert!(Search::new("zoe", SearchOptions::default()).matches("Zoë"));
+        assert!(Search::new("Zoë", SearchOptions::default()).matches("zoe"));
+        assert!(Search::new("creme brulee", SearchOptions::default()).matches("Crème Brûlée"));
+    }
+
+    #[test]
+    fn test_diacritic_sensitive() {
+        let options = SearchOptions {
+            diacritic_sensitive: true,
+            ..Default::default()
+        };
+        assert!(!Search::new("zoe", options).matches("Zoë"));
+        assert!(Search::new("zoë", options).matches("Zoë"));
+    }
+
+    #[test]
+    fn test_query_is_trimmed() {
+        let search = Search::new("  bob ", SearchOptions::default());
+        assert_eq!(search.query(), "  bob ");
+        assert!(search.matches("Bobby"));
+    }
+
+    #[test]
+    fn test_set_options_keeps_query() {
+        let mut search = Search::new("Ali", SearchOptions::default());
+        search.set_options(SearchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        });
+        assert_eq!(search.query(), "Ali");
+        assert!(!search.matches("khalil"));
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::HashSet;
+
+/// How many rows can be selected at once.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum SelectionMode {
+    /// At most one row is selected.
+    Single,
+    /// Any number of rows can be selected.
+    #[default]
+    Multi,
+}
+
+/// A user gesture to change the selection.
+///
+/// Used with [`RowData::select`](crate::RowData::select), typically mapped from click modifiers.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SelectionGesture {
+    /// Select only this row (plain click).
+    Replace,
+    /// Add or remove this row, keeping the rest of the selection (Ctrl/Cmd-click).
+    /// In [`SelectionMode::Single`], selecting a row deselects the previous one.
+    Toggle,
+    /// Select every row between the last clicked row and this one in the current
+    /// filtered and sorted order (Shift-click).
+    /// Behaves like `Replace` in [`SelectionMode::Single`] or when there is no anchor.
+    Range,
+}
+
+/// Manages the selected rows of a table, keyed by [`Row::key`](crate::Row::key).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Selection {
+    mode: SelectionMode,
+    /// Keys of the selected rows
+    keys: HashSet<String>,
+    /// The key of the last row selected by `Replace` or `Toggle`, where `Range` starts from
+    anchor: Option<String>,
+}
+
+impl Selection {
+    /// Creates an empty Selection with the given mode
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the selection mode
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Changes the selection mode.
+    /// Switching to Single keeps only the anchor row if it is selected.
+    pub fn set_mode(&mut self, mode: SelectionMode) {
+        self.mode = mode;
+        if mode == SelectionMode::Single && self.keys.len() > 1 {
+            let anchor = self.anchor.clone().filter(|key| self.keys.contains(key));
+            self.keys = anchor.into_iter().collect();
+        }
+    }
+
+    /// Checks if the row with the given key is selected
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the keys of the selected rows in no particular order
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.keys.iter()
+    }
+
+    /// Returns the number of selected rows
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Checks if no row is selected
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Applies a gesture on the row with the given key.
+    /// `order` is the keys of the filtered rows in display order, used by `Range`.
+    pub fn select(&mut self, key: &str, gesture: SelectionGesture, order: &[String]) {
+        match (gesture, self.mode) {
+            (SelectionGesture::Replace, _) | (SelectionGesture::Range, SelectionMode::Single) => {
+                self.keys.clear();
+                self.keys.insert(key.to_string());
+                self.anchor = Some(key.to_string());
+            }
+            (SelectionGesture::Toggle, mode) => {
+                if !self.keys.remove(key) {
+                    if mode == SelectionMode::Single {
+                        self.keys.clear();
+                    }
+                    self.keys.insert(key.to_string());
+                }
+                self.anchor = Some(key.to_string());
+            }
+            (SelectionGesture::Range, SelectionMode::Multi) => {
+                let anchor_pos = self
+                    .anchor
+                    .as_ref()
+                    .and_then(|anchor| order.iter().position(|k| k == anchor));
+                let target_pos = order.iter().position(|k| k == key);
+                match (anchor_pos, target_pos) {
+                    (Some(a), Some(b)) => {
+                        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+                        self.keys = order[start..=end].iter().cloned().collect();
+                    }
+                    // Without a usable anchor, start a new selection from this row
+                    _ => self.select(key, SelectionGesture::Replace, order),
+                }
+            }
+        }
+    }
+
+    /// Selects all the given rows. Only applies in Multi mode.
+    pub fn select_all(&mut self, keys: &[String]) {
+        if self.mode == SelectionMode::Multi {
+            self.keys.extend(keys.iter().cloned());
+        }
+    }
+
+    /// Inverts the selection state of the given rows. Only applies in Multi mode.
+    pub fn invert(&mut self, keys: &[String]) {
+        if self.mode == SelectionMode::Multi {
+            for key in keys {
+                if !self.keys.remove(key) {
+                    self.keys.insert(key.clone());
+                }
+            }
+        }
+    }
+
+    /// Deselects all rows
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.anchor = None;
+    }
+
+    /// Removes keys for which `exists` returns false.
+    /// Returns true if anything was removed.
+    pub fn retain(&mut self, exists: impl Fn(&str) -> bool) -> bool {
+        let len = self.keys.len();
+        self.keys.retain(|key| exists(key));
+        let anchor_removed = self.anchor.as_deref().is_some_and(|anchor| !exists(anchor));
+        if anchor_removed {
+            self.anchor = None;
+        }
+        len != self.keys.len() || anchor_removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|k| k.to_string()).collect()
+    }
+
+    fn sorted(selection: &Selection) -> Vec<&str> {
+        let mut keys: Vec<&str> = selection.keys().map(|k| k.as_str()).collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_default() {
+        let selection = Selection::default();
+        assert_eq!(selection.mode(), SelectionMode::Multi);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_replace() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Replace, &order);
+        selection.select("b", SelectionGesture::Replace, &order);
+        assert_eq!(sorted(&selection), ["b"]);
+    }
+
+    #[test]
+    fn test_toggle_multi() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Toggle, &order);
+        selection.select("c", SelectionGesture::Toggle, &order);
+        assert_eq!(sorted(&selection), ["a", "c"]);
+
+        selection.select("a", SelectionGesture::Toggle, &order);
+        assert_eq!(sorted(&selection), ["c"]);
+    }
+
+    #[test]
+    fn test_toggle_single() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::new(SelectionMode::Single);
+
+        selection.select("a", SelectionGesture::Toggle, &order);
+        selection.select("b", SelectionGesture::Toggle, &order);
+        assert_eq!(sorted(&selection), ["b"]);
+
+        selection.select("b", SelectionGesture::Toggle, &order);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_range() {
+        let order = order(&["a", "b", "c", "d", "e"]);
+        let mut selection = Selection::default();
+
+        selection.select("b", SelectionGesture::Replace, &order);
+        selection.select("d", SelectionGesture::Range, &order);
+        assert_eq!(sorted(&selection), ["b", "c", "d"]);
+
+        // The anchor stays on "b"; a backwards range replaces the previous one
+        selection.select("a", SelectionGesture::Range, &order);
+        assert_eq!(sorted(&selection), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_range_follows_given_order() {
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Replace, &order(&["a", "b", "c"]));
+        // After a re-sort, the range covers the rows between them in the new order
+        selection.select("b", SelectionGesture::Range, &order(&["c", "a", "e", "b"]));
+        assert_eq!(sorted(&selection), ["a", "b", "e"]);
+    }
+
+    #[test]
+    fn test_range_without_anchor() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::default();
+
+        selection.select("b", SelectionGesture::Range, &order);
+        assert_eq!(sorted(&selection), ["b"]);
+    }
+
+    #[test]
+    fn test_range_single() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::new(SelectionMode::Single);
+
+        selection.select("a", SelectionGesture::Replace, &order);
+        selection.select("c", SelectionGesture::Range, &order);
+        assert_eq!(sorted(&selection), ["c"]);
+    }
+
+    #[test]
+    fn test_select_all_and_invert() {
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Replace, &order(&["a", "b", "c"]));
+        selection.invert(&order(&["a", "b", "c"]));
+        assert_eq!(sorted(&selection), ["b", "c"]);
+
+        selection.select_all(&order(&["a", "b"]));
+        assert_eq!(sorted(&selection), ["a", "b", "c"]);
+
+        selection.clear();
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_select_all_single_is_noop() {
+        let mut selection = Selection::new(SelectionMode::Single);
+        selection.select_all(&order(&["a", "b"]));
+        selection.invert(&order(&["a", "b"]));
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_switch_to_single_keeps_anchor() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Toggle, &order);
+        selection.select("c", SelectionGesture::Toggle, &order);
+        selection.set_mode(SelectionMode::Single);
+        assert_eq!(sorted(&selection), ["c"]);
+    }
+
+    #[test]
+    fn test_retain() {
+        let order = order(&["a", "b", "c"]);
+        let mut selection = Selection::default();
+
+        selection.select("a", SelectionGesture::Toggle, &order);
+        selection.select("b", SelectionGesture::Toggle, &order);
+
+        assert!(!selection.retain(|_| true));
+        assert!(selection.retain(|key| key != "b"));
+        assert_eq!(sorted(&selection), ["a"]);
+
+        // Range falls back to Replace once the anchor is gone
+        selection.select("c", SelectionGesture::Range, &order);
+        assert_eq!(sorted(&selection), ["c"]);
+    }
+}
@@ -0,0 +1,289 @@
+use super::{SortDirection, SortState, TableState};
+use std::collections::BTreeMap;
+
+/// The query parameter holding the sorts, e.g. `sort=-priority,title`.
+const SORT_PARAM: &str = "sort";
+/// The query parameter holding the visible columns, e.g. `cols=title,status`.
+const COLUMNS_PARAM: &str = "cols";
+
+/// A compact, URL-friendly form of a table's sorts and column layout.
+///
+/// Encodes as a query string like `sort=-priority,title&cols=title,status`:
+///
+/// - `sort` lists the sorted columns in priority order; a leading `-` means descending.
+/// - `cols` lists the visible columns in display order; all other columns are hidden.
+///
+/// Either parameter is omitted when it has nothing to say (no sorts, or the default layout).
+/// Parsing ignores other parameters and malformed values, so the query of a page URL can be
+/// passed as a whole. Columns are identified by
+/// [`TableColumn::column_name`](crate::TableColumn::column_name), and names the table does not
+/// know are ignored when the query is applied.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::{SortDirection, TableQuery};
+///
+/// let query = TableQuery::parse("?page=2&sort=-priority,title&cols=title,status");
+/// assert_eq!(query.sorts[0].column, "priority");
+/// assert_eq!(query.sorts[0].direction, SortDirection::Descending);
+/// assert_eq!(query.columns, Some(vec!["title".to_string(), "status".to_string()]));
+///
+/// assert_eq!(query.to_query_string(), "sort=-priority,title&cols=title,status");
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TableQuery {
+    /// The sorted columns in priority order.
+    pub sorts: Vec<SortState>,
+    /// The visible columns in display order, or `None` to keep the default layout.
+    pub columns: Option<Vec<String>>,
+}
+
+impl TableQuery {
+    /// Parses the table parameters of a query string, with or without the leading `?`.
+    pub fn parse(query: &str) -> Self {
+        let mut result = Self::default();
+        let query = query.strip_prefix('?').unwrap_or(query);
+        for param in query.split('&') {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            match key {
+                SORT_PARAM => {
+                    result.sorts = split_list(value)
+                        .map(|item| match item.strip_prefix('-') {
+                            Some(name) => (name, SortDirection::Descending),
+                            None => (item, SortDirection::Ascending),
+                        })
+                        .filter(|(name, _)| !name.is_empty())
+                        .map(|(name, direction)| SortState {
+                            column: decode(name),
+                            direction,
+                        })
+                        .collect();
+                }
+                COLUMNS_PARAM => {
+                    result.columns = Some(split_list(value).map(decode).collect());
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Encodes this query as a query string without the leading `?`.
+    ///
+    /// Returns an empty string if there is nothing to encode.
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+        if !self.sorts.is_empty() {
+            let sorts: Vec<String> = self
+                .sorts
+                .iter()
+                .map(|sort| match sort.direction {
+                    SortDirection::Ascending => encode(&sort.column),
+                    SortDirection::Descending => format!("-{}", encode(&sort.column)),
+                })
+                .collect();
+            params.push(format!("{SORT_PARAM}={}", sorts.join(",")));
+        }
+        if let Some(columns) = &self.columns {
+            let columns: Vec<String> = columns.iter().map(|name| encode(name)).collect();
+            params.push(format!("{COLUMNS_PARAM}={}", columns.join(",")));
+        }
+        params.join("&")
+    }
+
+    /// Creates a query from a table state, omitting `cols` if the layout is the default one
+    /// for the given column names.
+    pub(crate) fn from_state(state: &TableState, column_names: &[String]) -> Self {
+        let is_default_layout = state.hidden.is_empty() && state.order == column_names;
+        Self {
+            sorts: state.sorts.clone(),
+            columns: (!is_default_layout).then(|| state.order.clone()),
+        }
+    }
+
+    /// Converts this query into a table state for the given column names.
+    ///
+    /// Columns not listed in `cols` are hidden. If `cols` is missing or names no known column,
+    /// all columns are shown in their natural order.
+    pub(crate) fn into_state(self, column_names: &[String]) -> TableState {
+        let order = match self.columns {
+            Some(columns) if columns.iter().any(|name| column_names.contains(name)) => columns,
+            _ => column_names.to_vec(),
+        };
+        let hidden = column_names
+            .iter()
+            .filter(|name| !order.contains(name))
+            .cloned()
+            .collect();
+        TableState {
+            sorts: self.sorts,
+            order,
+            hidden,
+            pinned_left: vec![],
+            pinned_right: vec![],
+            widths: BTreeMap::new(),
+        }
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').filter(|item| !item.is_empty())
+}
+
+/// Percent-encodes everything except ASCII letters, digits, `_`, `.` and `~`.
+///
+/// `-` is encoded so that it only ever marks a descending sort.
+fn encode(name: &str) -> String {
+    let mut encoded = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Decodes percent-encoded bytes and `+` as a space. Malformed escapes are kept as is.
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = value
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                if let Some(byte) = hex {
+                    decoded.push(byte);
+                    i += 2;
+                } else {
+                    decoded.push(b'%');
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn sort(column: &str, direction: SortDirection) -> SortState {
+        SortState {
+            column: column.to_string(),
+            direction,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let query = TableQuery::parse("sort=-priority,title&cols=title,status");
+        assert_eq!(
+            query.sorts,
+            [
+                sort("priority", SortDirection::Descending),
+                sort("title", SortDirection::Ascending)
+            ]
+        );
+        assert_eq!(query.columns, Some(names(&["title", "status"])));
+    }
+
+    #[test]
+    fn test_parse_ignores_other_params() {
+        let query = TableQuery::parse("?page=2&sort=title&q=hello");
+        assert_eq!(query.sorts, [sort("title", SortDirection::Ascending)]);
+        assert_eq!(query.columns, None);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(TableQuery::parse(""), TableQuery::default());
+        assert_eq!(
+            TableQuery::parse("&&sort&cols"),
+            TableQuery {
+                sorts: vec![],
+                columns: Some(vec![]),
+            }
+        );
+        // Empty items and a lone "-" are skipped
+        let query = TableQuery::parse("sort=,-,title,");
+        assert_eq!(query.sorts, [sort("title", SortDirection::Ascending)]);
+    }
+
+    #[test]
+    fn test_encode() {
+        let query = TableQuery {
+            sorts: vec![sort("days-until", SortDirection::Descending)],
+            columns: Some(names(&["a,b", "due date", "naïve"])),
+        };
+        let encoded = query.to_query_string();
+        assert_eq!(
+            encoded,
+            "sort=-days%2Duntil&cols=a%2Cb,due%20date,na%C3%AFve"
+        );
+        assert_eq!(TableQuery::parse(&encoded), query);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("due+date"), "due date");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn test_empty_query_string() {
+        assert_eq!(TableQuery::default().to_query_string(), "");
+    }
+
+    #[test]
+    fn test_from_state_omits_default_layout() {
+        let columns = names(&["title", "status"]);
+        let state = TableState {
+            sorts: vec![sort("status", SortDirection::Ascending)],
+            order: columns.clone(),
+            hidden: vec![],
+            ..Default::default()
+        };
+        assert_eq!(
+            TableQuery::from_state(&state, &columns).to_query_string(),
+            "sort=status"
+        );
+
+        let state = TableState {
+            sorts: vec![],
+            order: names(&["status"]),
+            hidden: names(&["title"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            TableQuery::from_state(&state, &columns).to_query_string(),
+            "cols=status"
+        );
+    }
+
+    #[test]
+    fn test_into_state() {
+        let columns = names(&["title", "priority", "status"]);
+        let state = TableQuery::parse("cols=status,unknown,title").into_state(&columns);
+        assert_eq!(state.order, ["status", "unknown", "title"]);
+        assert_eq!(state.hidden, ["priority"]);
+
+        // Without any known column, the default layout is kept
+        let state = TableQuery::parse("cols=unknown").into_state(&columns);
+        assert_eq!(state.order, columns);
+        assert!(state.hidden.is_empty());
+    }
+}
@@ -0,0 +1,355 @@
+use super::{ColumnOrder, ColumnWidths, PinState, Sort, SortDirection, SortRecord};
+use std::collections::BTreeMap;
+
+/// A snapshot of a table's view state: sorts, column order, visibility, pinning and widths.
+///
+/// Columns are identified by [`TableColumn::column_name`](crate::TableColumn::column_name)
+/// rather than by their position in the columns tuple, so a state saved by one version of a
+/// table can be restored after columns were added, removed or reordered in code:
+///
+/// - Sorts and columns whose names no longer exist are ignored.
+/// - Columns that did not exist when the state was saved are shown after the saved columns.
+///
+/// Enable the `serde` feature to serialize it.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let users = use_signal(|| vec![User { id: 1 }]);
+/// #     let data = use_tabular((Col,), users.into());
+/// let mut saved = use_signal(|| None::<TableState>);
+/// rsx! {
+///     button { onclick: move |_| saved.set(Some(data.context.snapshot())), "Save layout" }
+///     button {
+///         onclick: move |_| {
+///             if let Some(state) = saved() {
+///                 data.context.restore(&state);
+///             }
+///         },
+///         "Restore layout"
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TableState {
+    /// The sorted columns in priority order (the first one is the primary sort).
+    pub sorts: Vec<SortState>,
+    /// The names of the visible columns in display order.
+    pub order: Vec<String>,
+    /// The names of the hidden columns.
+    pub hidden: Vec<String>,
+    /// The names of the columns pinned to the left edge.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub pinned_left: Vec<String>,
+    /// The names of the columns pinned to the right edge.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub pinned_right: Vec<String>,
+    /// The widths in pixels of the resized columns, by column name. Other columns keep their
+    /// [`default_width`](crate::TableColumn::default_width).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub widths: BTreeMap<String, f64>,
+}
+
+/// The sort of a single column in a [`TableState`].
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortState {
+    /// The name of the sorted column.
+    pub column: String,
+    /// The direction of the sort.
+    pub direction: SortDirection,
+}
+
+impl TableState {
+    /// Captures the state of a table with the given column names.
+    pub(crate) fn capture(
+        column_names: &[String],
+        sorts: &[SortRecord],
+        order: &ColumnOrder,
+        widths: &ColumnWidths,
+    ) -> Self {
+        let pinned = |pin: PinState| {
+            (0..column_names.len())
+                .filter(|&col| order.pin_state(col) == pin)
+                .map(|col| column_names[col].clone())
+                .collect()
+        };
+        Self {
+            sorts: sorts
+                .iter()
+                .map(|record| SortState {
+                    column: column_names[record.column].clone(),
+                    direction: record.sort.direction,
+                })
+                .collect(),
+            order: order
+                .get_order()
+                .iter()
+                .map(|&col| column_names[col].clone())
+                .collect(),
+            hidden: (0..column_names.len())
+                .filter(|&col| !order.is_visible(col))
+                .map(|col| column_names[col].clone())
+                .collect(),
+            pinned_left: pinned(PinState::Left),
+            pinned_right: pinned(PinState::Right),
+            widths: (0..column_names.len())
+                .filter(|&col| !widths.is_default(col))
+                .filter_map(|col| Some((column_names[col].clone(), widths.get(col)?)))
+                .collect(),
+        }
+    }
+
+    /// Resolves the column names against the current columns of a table.
+    ///
+    /// Returns the sort records and the visible columns in display order.
+    pub(crate) fn resolve(&self, column_names: &[String]) -> (Vec<SortRecord>, Vec<usize>) {
+        let index_of = |name: &str| column_names.iter().position(|n| n == name);
+
+        let mut sorts: Vec<SortRecord> = Vec::new();
+        for sort in &self.sorts {
+            if let Some(column) = index_of(&sort.column)
+                && !sorts.iter().any(|record| record.column == column)
+            {
+                sorts.push(SortRecord {
+                    column,
+                    sort: Sort {
+                        direction: sort.direction,
+                    },
+                });
+            }
+        }
+
+        let mut order: Vec<usize> = Vec::new();
+        for name in &self.order {
+            if let Some(col) = index_of(name)
+                && !order.contains(&col)
+            {
+                order.push(col);
+            }
+        }
+        // Columns unknown to this state were added after it was saved
+        let is_known = |name: &String| self.order.contains(name) || self.hidden.contains(name);
+        order.extend((0..column_names.len()).filter(|&col| !is_known(&column_names[col])));
+
+        (sorts, order)
+    }
+
+    /// Resolves the pinned column names against the current columns of a table.
+    ///
+    /// Returns the pin state of every column, unpinned if not pinned in this state.
+    pub(crate) fn resolve_pins(&self, column_names: &[String]) -> Vec<(usize, PinState)> {
+        column_names
+            .iter()
+            .enumerate()
+            .map(|(col, name)| {
+                let pin = if self.pinned_left.contains(name) {
+                    PinState::Left
+                } else if self.pinned_right.contains(name) {
+                    PinState::Right
+                } else {
+                    PinState::Unpinned
+                };
+                (col, pin)
+            })
+            .collect()
+    }
+
+    /// Resolves the column widths against the current columns of a table.
+    ///
+    /// Returns the saved widths of the columns that still exist.
+    pub(crate) fn resolve_widths(&self, column_names: &[String]) -> Vec<(usize, f64)> {
+        column_names
+            .iter()
+            .enumerate()
+            .filter_map(|(col, name)| Some((col, *self.widths.get(name)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn sort(column: &str, direction: SortDirection) -> SortState {
+        SortState {
+            column: column.to_string(),
+            direction,
+        }
+    }
+
+    fn resolved_sorts(records: &[SortRecord]) -> Vec<(usize, SortDirection)> {
+        records
+            .iter()
+            .map(|record| (record.column, record.sort.direction))
+            .collect()
+    }
+
+    #[test]
+    fn test_capture() {
+        let columns = names(&["title", "priority", "status"]);
+        let sorts = [
+            SortRecord {
+                column: 1,
+                sort: Sort {
+                    direction: SortDirection::Descending,
+                },
+            },
+            SortRecord {
+                column: 0,
+                sort: Sort {
+                    direction: SortDirection::Ascending,
+                },
+            },
+        ];
+        let mut order = ColumnOrder::new(3);
+        order.set_order([2, 0]);
+        let mut widths =
+            ColumnWidths::new(vec![None, Some(80.0), None], vec![None; 3], vec![None; 3]);
+
+        let state = TableState::capture(&columns, &sorts, &order, &widths);
+        assert_eq!(
+            state.sorts,
+            [
+                sort("priority", SortDirection::Descending),
+                sort("title", SortDirection::Ascending)
+            ]
+        );
+        assert_eq!(state.order, ["status", "title"]);
+        assert_eq!(state.hidden, ["priority"]);
+        assert!(state.pinned_left.is_empty() && state.pinned_right.is_empty());
+
+        order.set_pin_state(0, PinState::Left);
+        order.set_pin_state(1, PinState::Right);
+        let state = TableState::capture(&columns, &sorts, &order, &widths);
+        assert_eq!(state.order, ["title", "status"]);
+        assert_eq!(state.pinned_left, ["title"]);
+        // Hidden columns keep their pin
+        assert_eq!(state.pinned_right, ["priority"]);
+
+        // Only resized columns are captured
+        assert!(state.widths.is_empty());
+        widths.set(2, 120.0);
+        let state = TableState::capture(&columns, &sorts, &order, &widths);
+        assert_eq!(
+            state.widths,
+            BTreeMap::from([("status".to_string(), 120.0)])
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let state = TableState {
+            sorts: vec![sort("status", SortDirection::Descending)],
+            order: names(&["status", "title"]),
+            hidden: names(&["priority"]),
+            ..Default::default()
+        };
+        let (sorts, order) = state.resolve(&names(&["title", "priority", "status"]));
+        assert_eq!(resolved_sorts(&sorts), [(2, SortDirection::Descending)]);
+        assert_eq!(order, [2, 0]);
+    }
+
+    #[test]
+    fn test_resolve_removed_columns() {
+        let state = TableState {
+            sorts: vec![
+                sort("removed", SortDirection::Ascending),
+                sort("title", SortDirection::Ascending),
+            ],
+            order: names(&["removed", "title"]),
+            hidden: names(&["also_removed"]),
+            ..Default::default()
+        };
+        let (sorts, order) = state.resolve(&names(&["title"]));
+        assert_eq!(resolved_sorts(&sorts), [(0, SortDirection::Ascending)]);
+        assert_eq!(order, [0]);
+    }
+
+    #[test]
+    fn test_resolve_added_columns() {
+        let state = TableState {
+            sorts: vec![],
+            order: names(&["status", "title"]),
+            hidden: names(&["priority"]),
+            ..Default::default()
+        };
+        let (_, order) = state.resolve(&names(&["new", "title", "priority", "status", "days"]));
+        // New columns are shown after the saved ones, in their natural order
+        assert_eq!(order, [3, 1, 0, 4]);
+    }
+
+    #[test]
+    fn test_resolve_duplicates() {
+        let state = TableState {
+            sorts: vec![
+                sort("title", SortDirection::Ascending),
+                sort("title", SortDirection::Descending),
+            ],
+            order: names(&["title", "title"]),
+            hidden: vec![],
+            ..Default::default()
+        };
+        let (sorts, order) = state.resolve(&names(&["title"]));
+        assert_eq!(resolved_sorts(&sorts), [(0, SortDirection::Ascending)]);
+        assert_eq!(order, [0]);
+    }
+
+    #[test]
+    fn test_resolve_pins() {
+        let state = TableState {
+            pinned_left: names(&["title", "removed"]),
+            pinned_right: names(&["status"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            state.resolve_pins(&names(&["status", "new", "title"])),
+            [
+                (0, PinState::Right),
+                (1, PinState::Unpinned),
+                (2, PinState::Left)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_widths() {
+        let state = TableState {
+            widths: BTreeMap::from([("title".to_string(), 90.0), ("removed".to_string(), 10.0)]),
+            ..Default::default()
+        };
+        assert_eq!(
+            state.resolve_widths(&names(&["status", "title"])),
+            [(1, 90.0)]
+        );
+    }
+
+    #[test]
+    fn test_default_resolves_to_default_layout() {
+        let (sorts, order) = TableState::default().resolve(&names(&["a", "b"]));
+        assert!(sorts.is_empty());
+        assert_eq!(order, [0, 1]);
+    }
+}
@@ -0,0 +1,223 @@
+//! Tests for pinning columns to the left and right edges
+
+use super::tests_rows_filter_and_sort::Person;
+use super::*;
+use crate::TableColumn;
+use crate::test_suite::test_hook;
+use dioxus::core::AttributeValue;
+
+/// A column with a fixed width
+#[derive(Clone, PartialEq)]
+struct WidthColumn {
+    name: &'static str,
+    width: Option<f64>,
+}
+
+impl TableColumn<Person> for WidthColumn {
+    fn column_name(&self) -> String {
+        self.name.into()
+    }
+
+    fn render_header(&self, _context: ColumnContext, attributes: Vec<Attribute>) -> Element {
+        rsx! {
+            th { ..attributes }
+        }
+    }
+
+    fn render_cell(
+        &self,
+        _context: ColumnContext,
+        _row: &Person,
+        attributes: Vec<Attribute>,
+    ) -> Element {
+        rsx! {
+            td { ..attributes }
+        }
+    }
+
+    fn default_width(&self) -> Option<f64> {
+        self.width
+    }
+}
+
+fn columns() -> (WidthColumn, WidthColumn, WidthColumn, WidthColumn) {
+    let column = |name, width| WidthColumn { name, width };
+    (
+        column("a", Some(100.0)),
+        column("b", Some(50.0)),
+        column("c", None),
+        column("d", Some(30.0)),
+    )
+}
+
+/// Returns the pin attributes of a column as `name: value` strings
+fn pin_attributes<C>(context: TableContext<C>, column: usize) -> Vec<String> {
+    context
+        .data
+        .pin_attributes(column)
+        .iter()
+        .map(|attribute| match &attribute.value {
+            AttributeValue::Text(value) => format!("{}: {value}", attribute.name),
+            _ => panic!("Unexpected value of {}", attribute.name),
+        })
+        .collect()
+}
+
+#[test]
+fn test_pin_with_column_context() {
+    test_hook(
+        || TableContext::use_table_context::<Person>(columns()),
+        |context, proxy| match proxy.generation {
+            0 => {
+                let column = |col| context.data.column_context(col);
+                column(2).pin_left();
+                assert_eq!(column(2).pin_state(), PinState::Left);
+                assert_eq!(context.data.get_column_order(), [2, 0, 1, 3]);
+
+                column(0).pin_right();
+                assert_eq!(context.data.get_column_order(), [2, 1, 3, 0]);
+
+                // Moves stay within the region of the column
+                column(1).move_to(0);
+                assert_eq!(context.data.get_column_order(), [2, 1, 3, 0]);
+                column(0).move_to(0);
+                assert_eq!(context.data.get_column_order(), [2, 1, 3, 0]);
+                column(2).swap_with(3);
+                assert_eq!(context.data.get_column_order(), [2, 1, 3, 0]);
+
+                column(0).unpin();
+                assert_eq!(column(0).pin_state(), PinState::Unpinned);
+                assert_eq!(context.data.get_column_order(), [2, 1, 3, 0]);
+
+                column(0).reset_order();
+                assert_eq!(column(2).pin_state(), PinState::Unpinned);
+                assert_eq!(context.data.get_column_order(), [0, 1, 2, 3]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_pin_attributes() {
+    test_hook(
+        || TableContext::use_table_context::<Person>(columns()),
+        |context, proxy| match proxy.generation {
+            0 => {
+                assert!(pin_attributes(context, 0).is_empty());
+
+                context.data.pin_column(1, PinState::Left);
+                context.data.pin_column(0, PinState::Left);
+                // Columns pinned to the right are added before the ones already there
+                context.data.pin_column(3, PinState::Right);
+                context.data.pin_column(2, PinState::Right);
+                assert_eq!(context.data.get_column_order(), [1, 0, 2, 3]);
+
+                assert_eq!(
+                    pin_attributes(context, 1),
+                    [
+                        "position: sticky",
+                        "left: 0px",
+                        "z-index: 1",
+                        "data-pinned: left"
+                    ]
+                );
+                // Stacked after the 50px of the first pinned column
+                assert_eq!(pin_attributes(context, 0)[1], "left: 50px");
+                // Right offsets count from the right edge
+                assert_eq!(pin_attributes(context, 3)[1], "right: 0px");
+                assert_eq!(
+                    pin_attributes(context, 2),
+                    [
+                        "position: sticky",
+                        "right: 30px",
+                        "z-index: 1",
+                        "data-pinned: right"
+                    ]
+                );
+
+                // Hidden columns take no space
+                context.data.hide_column(1);
+                assert_eq!(pin_attributes(context, 0)[1], "left: 0px");
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_pin_columns_without_width() {
+    test_hook(
+        || {
+            let column = |name| WidthColumn { name, width: None };
+            TableContext::use_table_context::<Person>((column("a"), column("b"), column("c")))
+        },
+        |context, proxy| match proxy.generation {
+            0 => {
+                context.data.pin_column(0, PinState::Left);
+                context.data.pin_column(1, PinState::Left);
+                context.data.pin_column(2, PinState::Left);
+
+                // The first column sticks to the edge, but the offset of the next ones is unknown
+                assert_eq!(pin_attributes(context, 0)[1], "left: 0px");
+                assert_eq!(pin_attributes(context, 1), ["data-pinned: left"]);
+                assert_eq!(pin_attributes(context, 2), ["data-pinned: left"]);
+
+                context.data.set_column_width(0, 80.0);
+                assert_eq!(pin_attributes(context, 1)[1], "left: 80px");
+                assert_eq!(pin_attributes(context, 2), ["data-pinned: left"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_restore_pins() {
+    test_hook(
+        || {
+            let state = TableState {
+                order: vec!["a".into(), "b".into(), "c".into()],
+                hidden: vec!["d".into()],
+                pinned_right: vec!["a".into(), "d".into()],
+                ..Default::default()
+            };
+            TableContext::use_table_context_with_state::<Person>(columns(), move || Some(state))
+        },
+        |context, proxy| match proxy.generation {
+            0 => {
+                assert_eq!(context.data.get_column_order(), [1, 2, 0]);
+                assert_eq!(context.data.column_pin_state(3), PinState::Right);
+                let state = context.snapshot();
+                assert_eq!(state.pinned_right, ["a", "d"]);
+
+                context.data.reset_column_order();
+                context.restore(&state);
+                assert_eq!(context.snapshot(), state);
+                assert_eq!(context.data.get_column_order(), [1, 2, 0]);
+
+                // Query strings keep the current pins
+                context.apply_query_string("cols=a,c");
+                assert_eq!(context.data.get_column_order(), [2, 0]);
+                assert_eq!(context.snapshot().pinned_right, ["a", "d"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,202 @@
+//! Tests for column widths and resizing
+
+use super::tests_rows_filter_and_sort::Person;
+use super::*;
+use crate::TableColumn;
+use crate::test_suite::test_hook;
+use dioxus::core::AttributeValue;
+use std::collections::BTreeMap;
+
+/// A column with width hints
+#[derive(Clone, PartialEq)]
+struct SizedColumn {
+    name: &'static str,
+    width: Option<f64>,
+    min_width: Option<f64>,
+    max_width: Option<f64>,
+}
+
+impl TableColumn<Person> for SizedColumn {
+    fn column_name(&self) -> String {
+        self.name.into()
+    }
+
+    fn render_header(&self, _context: ColumnContext, attributes: Vec<Attribute>) -> Element {
+        rsx! {
+            th { ..attributes }
+        }
+    }
+
+    fn render_cell(
+        &self,
+        _context: ColumnContext,
+        _row: &Person,
+        attributes: Vec<Attribute>,
+    ) -> Element {
+        rsx! {
+            td { ..attributes }
+        }
+    }
+
+    fn default_width(&self) -> Option<f64> {
+        self.width
+    }
+
+    fn min_width(&self) -> Option<f64> {
+        self.min_width
+    }
+
+    fn max_width(&self) -> Option<f64> {
+        self.max_width
+    }
+}
+
+fn columns() -> (SizedColumn, SizedColumn) {
+    (
+        SizedColumn {
+            name: "a",
+            width: Some(100.0),
+            min_width: Some(40.0),
+            max_width: Some(300.0),
+        },
+        SizedColumn {
+            name: "b",
+            width: None,
+            min_width: None,
+            max_width: None,
+        },
+    )
+}
+
+/// Returns the attributes added to a column as `name: value` strings
+fn column_attributes(
+    context: TableContext<(SizedColumn, SizedColumn)>,
+    column: usize,
+) -> Vec<String> {
+    context
+        .data
+        .column_attributes(column)
+        .iter()
+        .map(|attribute| match &attribute.value {
+            AttributeValue::Text(value) => format!("{}: {value}", attribute.name),
+            _ => panic!("Unexpected value of {}", attribute.name),
+        })
+        .collect()
+}
+
+#[test]
+fn test_set_width_with_column_context() {
+    test_hook(
+        || TableContext::use_table_context::<Person>(columns()),
+        |context, proxy| match proxy.generation {
+            0 => {
+                let column = |col| context.data.column_context(col);
+                assert_eq!(column(0).width(), Some(100.0));
+                assert_eq!(column(1).width(), None);
+
+                column(0).set_width(150.0);
+                assert_eq!(column(0).width(), Some(150.0));
+                // Widths are kept within the column limits
+                column(0).set_width(10.0);
+                assert_eq!(column(0).width(), Some(40.0));
+                column(0).set_width(1000.0);
+                assert_eq!(column(0).width(), Some(300.0));
+
+                column(1).set_width(80.0);
+                assert_eq!(column(1).width(), Some(80.0));
+                column(1).reset_width();
+                assert_eq!(column(1).width(), None);
+                assert_eq!(column(0).width(), Some(300.0));
+
+                column(1).set_width(80.0);
+                column(1).reset_widths();
+                assert_eq!(column(0).width(), Some(100.0));
+                assert_eq!(column(1).width(), None);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_column_attributes() {
+    test_hook(
+        || TableContext::use_table_context::<Person>(columns()),
+        |context, proxy| match proxy.generation {
+            0 => {
+                assert_eq!(column_attributes(context, 0), ["width: 100px"]);
+                assert!(column_attributes(context, 1).is_empty());
+
+                // Pin offsets follow the current widths
+                context.data.pin_column(0, PinState::Left);
+                context.data.pin_column(1, PinState::Left);
+                context.data.set_column_width(0, 120.5);
+                assert_eq!(
+                    column_attributes(context, 1),
+                    [
+                        "position: sticky",
+                        "left: 120.5px",
+                        "z-index: 1",
+                        "data-pinned: left"
+                    ]
+                );
+                assert_eq!(column_attributes(context, 0)[0], "width: 120.5px");
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_save_and_restore_widths() {
+    test_hook(
+        || {
+            let state = TableState {
+                widths: BTreeMap::from([("b".to_string(), 70.0), ("removed".to_string(), 5.0)]),
+                ..Default::default()
+            };
+            TableContext::use_table_context_with_state::<Person>(columns(), move || Some(state))
+        },
+        |context, proxy| match proxy.generation {
+            0 => {
+                assert_eq!(context.data.column_width(0), Some(100.0));
+                assert_eq!(context.data.column_width(1), Some(70.0));
+
+                context.data.set_column_width(0, 1000.0);
+                let state = context.snapshot();
+                assert_eq!(
+                    state.widths,
+                    BTreeMap::from([("a".to_string(), 300.0), ("b".to_string(), 70.0)])
+                );
+
+                context.data.reset_column_widths();
+                assert!(context.snapshot().widths.is_empty());
+                context.restore(&state);
+                assert_eq!(context.snapshot(), state);
+
+                // Restoring a state without widths goes back to the default widths
+                context.restore(&TableState::default());
+                assert_eq!(context.data.column_width(0), Some(100.0));
+                assert_eq!(context.data.column_width(1), None);
+
+                // Query strings keep the current widths
+                context.data.set_column_width(1, 50.0);
+                context.apply_query_string("cols=b");
+                assert_eq!(context.data.column_width(1), Some(50.0));
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
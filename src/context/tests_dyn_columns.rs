@@ -0,0 +1,155 @@
+//! Tests for runtime-defined columns boxed as `DynTableColumn`
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::{AgeColumn, AgeFilter, NameColumn, Person};
+use super::*;
+use crate::DynTableColumn;
+use crate::test_suite::test_hook;
+
+const PEOPLE: &[(&str, u32)] = &[("Charlie", 25), ("Alice", 35), ("Bob", 30)];
+
+fn ascending() -> SortGesture {
+    SortGesture::AddFirst(Sort {
+        direction: SortDirection::Ascending,
+    })
+}
+
+#[test]
+fn test_dyn_columns() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            // Columns chosen at runtime, like from a schema
+            let mut columns: Vec<Box<dyn DynTableColumn<Person>>> = Vec::new();
+            for name in ["age", "name", "age"] {
+                columns.push(match name {
+                    "name" => Box::new(NameColumn::use_column(None)),
+                    _ => Box::new(AgeColumn::use_column(None)),
+                });
+            }
+            let context = TableContext::use_table_context(columns);
+            let data = context.table_data(rows.into());
+            (context, data)
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(
+                    context.columns.read().column_names(),
+                    ["age", "name", "age"]
+                );
+                let indices = || data.rows().map(|row| row.index).collect::<Vec<_>>();
+
+                context.data.column_context(1).request_sort(ascending());
+                assert_eq!(indices(), [1, 2, 0]);
+                context.data.column_context(2).request_sort(ascending());
+                assert_eq!(indices(), [0, 2, 1]);
+
+                context.data.column_context(1).move_to(0);
+                assert_eq!(context.data.get_column_order(), [1, 0, 2]);
+                context.data.column_context(2).hide();
+                assert_eq!(context.data.num_visible_columns(), 2);
+                for header in context.headers::<Person>() {
+                    let _ = header.render(vec![]);
+                }
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_dyn_columns_filter() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns: Vec<Box<dyn DynTableColumn<Person>>> = vec![
+                Box::new(NameColumn::use_column(None)),
+                Box::new(AgeColumn::use_column(Some(AgeFilter::MinAge(30)))),
+            ];
+            TableContext::use_table_context(columns).table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                let indices: Vec<usize> = data.rows().map(|row| row.index).collect();
+                assert_eq!(indices, [1, 2]);
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_dyn_columns_equality() {
+    test_hook(
+        || {
+            let filter = use_signal(|| None);
+            let name: Box<dyn DynTableColumn<Person>> = Box::new(NameColumn { filter });
+            let age: Box<dyn DynTableColumn<Person>> = Box::new(AgeColumn::use_column(None));
+            (name, age)
+        },
+        |(name, age), _| {
+            // Equal if of the same type and equal as that type
+            assert!(name == name.clone());
+            assert!(name != age);
+            let other_name: Box<dyn DynTableColumn<Person>> = Box::new(NameColumn {
+                filter: Signal::new(None),
+            });
+            assert!(name != other_name);
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_dyn_columns_follow_schema() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            // Only the "name" column is known on the first render
+            let schema = use_signal(|| vec!["name"]);
+            let name = NameColumn::use_column(None);
+            let age = AgeColumn::use_column(None);
+            let columns: Vec<Box<dyn DynTableColumn<Person>>> = schema
+                .read()
+                .iter()
+                .map(|&column| -> Box<dyn DynTableColumn<Person>> {
+                    match column {
+                        "name" => Box::new(name.clone()),
+                        _ => Box::new(age.clone()),
+                    }
+                })
+                .collect();
+            let context = TableContext::use_table_context(columns);
+            (schema, context, context.table_data(rows.into()))
+        },
+        |(mut schema, context, data), proxy| {
+            let indices = || data.rows().map(|row| row.index).collect::<Vec<_>>();
+            match proxy.generation {
+                0 => {
+                    context.data.column_context(0).request_sort(ascending());
+                    context.data.column_context(0).set_width(120.0);
+                    schema.set(vec!["age", "name"]);
+                }
+                // Setting the new columns re-renders once more
+                1 | 2 => {
+                    assert_eq!(context.columns.read().column_names(), ["age", "name"]);
+                    assert_eq!(context.data.num_columns(), 2);
+                    // The name column keeps its sort and width, and the new column comes after it
+                    assert_eq!(context.data.get_column_order(), [1, 0]);
+                    assert_eq!(context.data.column_context(1).width(), Some(120.0));
+                    assert_eq!(indices(), [1, 2, 0]);
+                    for header in context.headers::<Person>() {
+                        let _ = header.render(vec![]);
+                    }
+                }
+                _ => panic!("Unexpected generation: {}", proxy.generation),
+            }
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,102 @@
+//! Tests for expandable detail rows
+
+use super::tests_fixtures::{people_named, row};
+use super::tests_rows_filter_and_sort::{AgeColumn, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+
+const NAMES: &[&str] = &["Alice", "Bob", "Carol"];
+
+#[test]
+fn test_toggle_expanded() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                let alice = row(data, "Alice");
+                assert!(!alice.is_expanded());
+
+                alice.toggle_expanded();
+                row(data, "Bob").set_expanded(true);
+                assert!(alice.is_expanded());
+                assert!(row(data, "Bob").is_expanded());
+                assert!(!row(data, "Carol").is_expanded());
+
+                alice.toggle_expanded();
+                assert!(!alice.is_expanded());
+
+                data.collapse_all();
+                assert!(!row(data, "Bob").is_expanded());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_visible_column_count() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(row(data, "Alice").visible_column_count(), 2);
+
+                context.data.hide_column(1);
+                assert_eq!(row(data, "Alice").visible_column_count(), 1);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_prune_removed_rows() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (rows, context.table_data(rows.into()))
+        },
+        |(mut rows, data), proxy| match proxy.generation {
+            0 => {
+                row(data, "Alice").set_expanded(true);
+                row(data, "Bob").set_expanded(true);
+
+                rows.write().retain(|person| person.name != "Bob");
+                data.prune_removed_rows();
+                assert!(row(data, "Alice").is_expanded());
+
+                // A new row with the removed key starts collapsed
+                rows.write().push(Person {
+                    name: "Bob".to_string(),
+                    age: 21,
+                });
+                assert!(!row(data, "Bob").is_expanded());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,140 @@
+//! Tests for combining column filters with a FilterExpr
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::{AgeColumn, AgeFilter, NameColumn, NameFilter, Person};
+use super::*;
+use crate::test_suite::test_hook;
+
+const PEOPLE: &[(&str, u32)] = &[("Alice", 30), ("Adam", 40), ("Bob", 35), ("Carol", 25)];
+
+fn names<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<String> {
+    data.rows()
+        .map(|row| row.data().read().name.clone())
+        .collect()
+}
+
+#[test]
+fn test_default_is_and() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (
+                NameColumn::use_column(Some(NameFilter::StartsWith("A".to_string()))),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(35))),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                assert_eq!(data.filter_expr(), None);
+                assert_eq!(names(data), ["Adam"]);
+
+                // An explicit AND of all columns is the same as the default
+                data.set_filter_expr(Some(FilterExpr::all_columns([0, 1])));
+                assert_eq!(names(data), ["Adam"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_or_and_not() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (
+                NameColumn::use_column(Some(NameFilter::StartsWith("A".to_string()))),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(35))),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_filter_expr(Some(FilterExpr::column(0) | FilterExpr::column(1)));
+                assert_eq!(names(data), ["Alice", "Adam", "Bob"]);
+
+                data.set_filter_expr(Some(!FilterExpr::column(0) | !FilterExpr::column(1)));
+                assert_eq!(names(data), ["Alice", "Bob", "Carol"]);
+
+                // Columns missing from the expression still apply
+                data.set_filter_expr(Some(FilterExpr::column(1)));
+                assert_eq!(names(data), ["Adam"]);
+                data.set_filter_expr(Some(!FilterExpr::column(1)));
+                assert_eq!(names(data), ["Alice"]);
+
+                data.set_filter_expr(None);
+                assert_eq!(names(data), ["Adam"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_expr_follows_filter_changes() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let age = AgeColumn::use_column(None);
+            let columns = (NameColumn::use_column(None), age.clone());
+            let context = TableContext::use_table_context::<Person>(columns);
+            (age, context.table_data(rows.into()))
+        },
+        |(mut age, data), proxy| match proxy.generation {
+            0 => {
+                data.set_filter_expr(Some(!FilterExpr::column(1)));
+                // Without an active age filter, the expression doesn't remove any row
+                assert_eq!(names(data), ["Alice", "Adam", "Bob", "Carol"]);
+
+                age.filter.set(Some(AgeFilter::MinAge(35)));
+                assert_eq!(names(data), ["Alice", "Carol"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_or_with_inactive_filter() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (
+                NameColumn::use_column(Some(NameFilter::StartsWith("A".to_string()))),
+                AgeColumn::use_column(None),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // The inactive age filter doesn't make the OR match every row
+                data.set_filter_expr(Some(FilterExpr::column(0) | FilterExpr::column(1)));
+                assert_eq!(names(data), ["Alice", "Adam"]);
+
+                data.set_filter_expr(Some(!FilterExpr::column(0) | !FilterExpr::column(1)));
+                assert_eq!(names(data), ["Bob", "Carol"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,44 @@
+//! Row fixtures shared by the context tests
+
+use super::tests_rows_filter_and_sort::Person;
+use super::*;
+
+/// Creates people from `(name, age)` pairs
+pub fn people(entries: &[(&str, u32)]) -> Vec<Person> {
+    entries
+        .iter()
+        .map(|&(name, age)| Person {
+            name: name.to_string(),
+            age,
+        })
+        .collect()
+}
+
+/// Creates people with the given names, aged 20, 21 and so on
+pub fn people_named(names: &[&str]) -> Vec<Person> {
+    names
+        .iter()
+        .zip(20..)
+        .map(|(name, age)| Person {
+            name: name.to_string(),
+            age,
+        })
+        .collect()
+}
+
+/// Creates `count` people named "Person 00", "Person 01" and so on, aged 20, 21 and so on
+pub fn numbered_people(count: u32) -> Vec<Person> {
+    (0..count)
+        .map(|i| Person {
+            name: format!("Person {i:02}"),
+            age: 20 + i,
+        })
+        .collect()
+}
+
+/// Finds the filtered row of the person with the given name
+pub fn row<C: Columns<Person>>(data: TableData<C, Person>, name: &str) -> RowData<C, Person> {
+    data.filtered_rows()
+        .find(|row| row.data().read().name == name)
+        .unwrap()
+}
@@ -0,0 +1,112 @@
+//! Tests for column footers aggregating the filtered rows
+
+use super::tests_fixtures::numbered_people;
+use super::tests_rows_filter_and_sort::{Age, AgeColumn, AgeFilter, Person};
+use super::*;
+use crate::test_suite::test_hook;
+use crate::{GetRowData, TableColumn};
+
+/// Records the total age of the rows passed to its footer
+#[derive(Clone, PartialEq)]
+struct TotalAgeColumn {
+    total: Signal<Option<u32>>,
+}
+
+impl<R: Row + GetRowData<Age>> TableColumn<R> for TotalAgeColumn {
+    fn column_name(&self) -> String {
+        "total_age".into()
+    }
+
+    fn render_header(&self, _context: ColumnContext, _attributes: Vec<Attribute>) -> Element {
+        rsx! {
+            th {}
+        }
+    }
+
+    fn render_cell(
+        &self,
+        _context: ColumnContext,
+        _row: &R,
+        _attributes: Vec<Attribute>,
+    ) -> Element {
+        rsx! {
+            td {}
+        }
+    }
+
+    fn render_footer(
+        &self,
+        _context: ColumnContext,
+        rows: &mut dyn Iterator<Item = &R>,
+        _attributes: Vec<Attribute>,
+    ) -> Element {
+        let mut total = self.total;
+        total.set(Some(rows.map(|row| row.get().0).sum()));
+        rsx! {
+            td {}
+        }
+    }
+}
+
+#[test]
+fn test_footer_aggregates_filtered_rows() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(5));
+            let total = use_signal(|| None);
+            let columns = (
+                AgeColumn::use_column(Some(AgeFilter::MinAge(22))),
+                TotalAgeColumn { total },
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            (total, context.table_data(rows.into()))
+        },
+        |(total, data), proxy| match proxy.generation {
+            0 => {
+                // Pagination does not limit the aggregated rows
+                data.set_page_size(Some(1));
+                for footer in data.footers() {
+                    let _ = footer.render(vec![]);
+                }
+                assert_eq!(*total.peek(), Some(22 + 23 + 24));
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_footers_follow_column_order() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(5));
+            let total = use_signal(|| None);
+            let columns = (AgeColumn::use_column(None), TotalAgeColumn { total });
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                let keys: Vec<String> = data.footers().map(|footer| footer.key()).collect();
+                assert_eq!(keys, ["age", "total_age"]);
+
+                context.data.swap_columns(0, 1);
+                let keys: Vec<String> = data.footers().map(|footer| footer.key()).collect();
+                assert_eq!(keys, ["total_age", "age"]);
+
+                context.data.hide_column(0);
+                let keys: Vec<String> = data.footers().map(|footer| footer.key()).collect();
+                assert_eq!(keys, ["total_age"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
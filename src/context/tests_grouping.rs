@@ -0,0 +1,324 @@
+//! Tests for row grouping on top of the filter/sort pipeline
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::{Age, AgeColumn, AgeFilter, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+use crate::{GetRowData, TableColumn};
+
+const PEOPLE: &[(&str, u32)] = &[
+    ("Dave", 22),
+    ("Carol", 31),
+    ("Eve", 45),
+    ("Bob", 25),
+    ("Alice", 34),
+];
+
+/// Groups people by the decade of their age
+#[derive(Clone, PartialEq)]
+struct DecadeColumn;
+
+impl<R: Row + GetRowData<Age>> TableColumn<R> for DecadeColumn {
+    fn column_name(&self) -> String {
+        "decade".into()
+    }
+
+    fn render_header(&self, _context: ColumnContext, _attributes: Vec<Attribute>) -> Element {
+        rsx! {
+            th {}
+        }
+    }
+
+    fn render_cell(
+        &self,
+        _context: ColumnContext,
+        _row: &R,
+        _attributes: Vec<Attribute>,
+    ) -> Element {
+        rsx! {
+            td {}
+        }
+    }
+
+    fn group_key(&self, row: &R) -> Option<String> {
+        Some(format!("{}s", row.get().0 / 10 * 10))
+    }
+}
+
+/// Describes group headers as `depth:value(count)` and rows by name
+fn describe<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<String> {
+    data.grouped_rows()
+        .map(|item| match item {
+            TableItem::GroupHeader(header) => format!(
+                "{}:{}({})",
+                header.depth(),
+                header.value().unwrap_or("-"),
+                header.count()
+            ),
+            TableItem::Row(row) => row.data().read().name.clone(),
+        })
+        .collect()
+}
+
+fn header<C: Columns<Person>>(
+    data: TableData<C, Person>,
+    value: &str,
+) -> GroupHeaderData<C, Person> {
+    data.grouped_rows()
+        .find_map(|item| match item {
+            TableItem::GroupHeader(header) if header.value() == Some(value) => Some(header),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn ascending() -> Sort {
+    Sort {
+        direction: SortDirection::Ascending,
+    }
+}
+
+#[test]
+fn test_no_grouping() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, _| {
+            assert!(data.group_by().is_empty());
+            assert_eq!(describe(data), ["Dave", "Carol", "Eve", "Bob", "Alice"]);
+        },
+        |_| {},
+    );
+}
+
+#[test]
+fn test_group_and_sort_within_groups() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                data.set_group_by(vec![1]);
+                // Groups in order of first appearance
+                assert_eq!(
+                    describe(data),
+                    [
+                        "0:20s(2)", "Dave", "Bob", "0:30s(2)", "Carol", "Alice", "0:40s(1)", "Eve"
+                    ]
+                );
+
+                // Sorting by name orders the groups by their first row and the rows within groups
+                context
+                    .data
+                    .request_sort(0, SortGesture::AddFirst(ascending()));
+                assert_eq!(
+                    describe(data),
+                    [
+                        "0:30s(2)", "Alice", "Carol", "0:20s(2)", "Bob", "Dave", "0:40s(1)", "Eve"
+                    ]
+                );
+
+                // `rows` yields the same order without headers
+                let names: Vec<String> = data
+                    .rows()
+                    .map(|row| row.data().read().name.clone())
+                    .collect();
+                assert_eq!(names, ["Alice", "Carol", "Bob", "Dave", "Eve"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_collapse_group() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_group_by(vec![1]);
+
+                let twenties = header(data, "20s");
+                assert!(!twenties.is_collapsed());
+                twenties.toggle_collapsed();
+                assert!(twenties.is_collapsed());
+                assert_eq!(
+                    describe(data),
+                    ["0:20s(2)", "0:30s(2)", "Carol", "Alice", "0:40s(1)", "Eve"]
+                );
+
+                data.expand_all_groups();
+                assert!(!twenties.is_collapsed());
+
+                // Changing the group-by columns expands all groups
+                header(data, "40s").set_collapsed(true);
+                data.set_group_by(vec![1]);
+                assert!(!header(data, "40s").is_collapsed());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_nested_groups() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn, DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // The name column has no group key: its subgroups have no value
+                data.set_group_by(vec![1, 0]);
+                assert_eq!(describe(data)[..4], ["0:20s(2)", "1:-(2)", "Dave", "Bob"]);
+
+                // Collapsing the outer group hides its subgroups
+                header(data, "20s").toggle_collapsed();
+                assert_eq!(describe(data)[..2], ["0:20s(2)", "0:30s(2)"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_grouping_with_filter_and_pagination() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (
+                NameColumn::use_column(None),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(25))),
+                DecadeColumn,
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(2));
+                data.next_page();
+
+                // Grouping goes back to the first page
+                context.data.column_context(2).toggle_grouping();
+                assert_eq!(context.data.column_context(2).group_level(), Some(0));
+                assert_eq!(data.current_page(), 0);
+
+                // Dave is filtered out, so the 20s only count Bob. Headers take a place on the page.
+                assert_eq!(describe(data), ["0:30s(2)", "Carol"]);
+                assert_eq!(data.total_pages(), 4);
+
+                // The header of a group continued from the previous page is repeated
+                data.next_page();
+                assert_eq!(describe(data), ["0:30s(2)", "Alice", "0:40s(1)"]);
+
+                context.data.column_context(2).toggle_grouping();
+                assert_eq!(context.data.column_context(2).group_level(), None);
+                assert!(data.group_by().is_empty());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_collapsed_groups_keep_pages_full() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_group_by(vec![1]);
+                data.set_page_size(Some(3));
+                assert_eq!(describe(data), ["0:20s(2)", "Dave", "Bob"]);
+                assert_eq!(data.total_pages(), 3);
+
+                // The following groups move up instead of leaving the page empty
+                header(data, "20s").toggle_collapsed();
+                assert_eq!(describe(data), ["0:20s(2)", "0:30s(2)", "Carol"]);
+                assert_eq!(data.total_pages(), 2);
+                data.next_page();
+                assert_eq!(describe(data), ["0:30s(2)", "Alice", "0:40s(1)", "Eve"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_select_all_visible_follows_grouped_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), DecadeColumn);
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_group_by(vec![1]);
+                data.set_page_size(Some(3));
+                data.next_page();
+                header(data, "30s").toggle_collapsed();
+                assert_eq!(describe(data), ["0:30s(2)", "0:40s(1)", "Eve"]);
+
+                // Only the rows shown on the page count, not those of the collapsed group
+                assert_eq!(data.row_count(), 1);
+                let names: Vec<String> = data
+                    .rows_in_range(0..10)
+                    .map(|row| row.data().read().name.clone())
+                    .collect();
+                assert_eq!(names, ["Eve"]);
+                data.select_all_visible();
+                assert_eq!(data.selected_keys(), ["Eve_45"]);
+                data.invert_selection();
+                assert!(data.selected_keys().is_empty());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,194 @@
+//! Tests for wide and nested column tuples
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::Person;
+use super::*;
+use crate::test_suite::test_hook;
+use crate::{IntoColumns, NestedColumns, TableColumn, use_tabular};
+
+const PEOPLE: &[(&str, u32)] = &[("Charlie", 25), ("Alice", 35), ("Bob", 30)];
+
+/// A column knowing its index in the table, sorting by age at even indices and by name at odd ones
+#[derive(Clone, PartialEq)]
+struct Field(usize);
+
+impl TableColumn<Person> for Field {
+    fn column_name(&self) -> String {
+        format!("field{}", self.0)
+    }
+
+    fn render_header(&self, context: ColumnContext, _attributes: Vec<Attribute>) -> Element {
+        // The width identifies the column the context belongs to
+        assert_eq!(context.width(), self.default_width());
+        rsx! {
+            th {}
+        }
+    }
+
+    fn render_cell(
+        &self,
+        context: ColumnContext,
+        _row: &Person,
+        _attributes: Vec<Attribute>,
+    ) -> Element {
+        assert_eq!(context.width(), self.default_width());
+        rsx! {
+            td {}
+        }
+    }
+
+    fn compare(&self, a: &Person, b: &Person) -> std::cmp::Ordering {
+        if self.0.is_multiple_of(2) {
+            a.age.cmp(&b.age)
+        } else {
+            a.name.cmp(&b.name)
+        }
+    }
+
+    fn default_width(&self) -> Option<f64> {
+        Some(100.0 + self.0 as f64)
+    }
+}
+
+fn indices<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<usize> {
+    data.rows().map(|row| row.index).collect()
+}
+
+/// Renders every header and cell, checking that each gets the context of its own column
+fn render_all<C: Columns<Person>>(data: TableData<C, Person>) {
+    for header in data.context.headers() {
+        let _ = header.render(vec![]);
+    }
+    for row in data.rows() {
+        for cell in row.cells() {
+            let _ = cell.render(vec![]);
+        }
+    }
+}
+
+#[test]
+fn test_wide_columns() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            use_tabular(
+                (
+                    Field(0),
+                    Field(1),
+                    Field(2),
+                    Field(3),
+                    Field(4),
+                    Field(5),
+                    Field(6),
+                    Field(7),
+                    Field(8),
+                    Field(9),
+                    Field(10),
+                    Field(11),
+                    Field(12),
+                    Field(13),
+                    Field(14),
+                    Field(15),
+                    Field(16),
+                ),
+                rows.into(),
+            )
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                let names = data.context.columns.read().column_names();
+                assert_eq!(names.len(), 17);
+                assert_eq!(names[12], "field12");
+                assert_eq!(data.context.data.get_column_name(16), "field16");
+                render_all(data);
+
+                data.context.data.request_sort(
+                    15,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+                assert_eq!(indices(data), [1, 2, 0]);
+                data.context.data.request_sort(
+                    16,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+                assert_eq!(indices(data), [0, 2, 1]);
+
+                data.context.data.hide_column(16);
+                assert_eq!(data.context.data.num_visible_columns(), 16);
+                data.context.data.swap_columns(0, 15);
+                render_all(data);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_nested_columns() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let key_columns = (Field(0), Field(1), Field(2));
+            let detail_columns = (Field(3), Field(4));
+            use_tabular((key_columns, detail_columns), rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // The sets are flattened in order, with continuous indices
+                assert_eq!(
+                    data.context.columns.read().column_names(),
+                    ["field0", "field1", "field2", "field3", "field4"]
+                );
+                render_all(data);
+
+                data.context.data.request_sort(
+                    3,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+                assert_eq!(indices(data), [1, 2, 0]);
+                data.context.data.request_sort(
+                    4,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+                assert_eq!(indices(data), [0, 2, 1]);
+
+                data.context.data.column_context(4).move_to(0);
+                render_all(data);
+                assert_eq!(data.context.data.column_context(4).width(), Some(104.0));
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_deeply_nested_columns() {
+    let columns = ((Field(0),), ((Field(1), Field(2)), (Field(3),)));
+    let columns: NestedColumns<_> = IntoColumns::<Person, _>::into_columns(columns);
+    assert_eq!(
+        columns.column_names(),
+        ["field0", "field1", "field2", "field3"]
+    );
+    assert_eq!(
+        columns.default_widths(),
+        [Some(100.0), Some(101.0), Some(102.0), Some(103.0)]
+    );
+    assert!(columns.filter(&people(PEOPLE)[0]));
+    assert_eq!(columns.compare().len(), 4);
+}
@@ -0,0 +1,178 @@
+//! Tests for pagination on top of the filter/sort pipeline
+
+use super::tests_fixtures::numbered_people;
+use super::tests_rows_filter_and_sort::{AgeColumn, AgeFilter, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+
+fn collect_indices<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<usize> {
+    data.rows().map(|row| row.index).collect()
+}
+
+fn descending() -> Sort {
+    Sort {
+        direction: SortDirection::Descending,
+    }
+}
+
+#[test]
+fn test_pagination_disabled_by_default() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(5));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                assert_eq!(data.page_size(), None);
+                assert_eq!(data.total_pages(), 1);
+                assert_eq!(data.current_page(), 0);
+                assert_eq!(collect_indices(data), vec![0, 1, 2, 3, 4]);
+            }
+            _ => panic!("Unexpected generation"),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_rows_are_sliced_by_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(7));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(3));
+                assert_eq!(data.filtered_row_count(), 7);
+                assert_eq!(data.total_pages(), 3);
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+                assert!(!data.has_prev_page());
+                assert!(data.has_next_page());
+
+                data.next_page();
+                assert_eq!(data.current_page(), 1);
+                assert_eq!(collect_indices(data), vec![3, 4, 5]);
+
+                data.go_to_page(10);
+                assert_eq!(data.current_page(), 2);
+                assert_eq!(collect_indices(data), vec![6]);
+                assert!(!data.has_next_page());
+
+                data.prev_page();
+                assert_eq!(collect_indices(data), vec![3, 4, 5]);
+
+                // filtered_rows ignores pagination
+                let all: Vec<usize> = data.filtered_rows().map(|row| row.index).collect();
+                assert_eq!(all, vec![0, 1, 2, 3, 4, 5, 6]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_page_is_clamped_when_filter_shrinks_result() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(7));
+            let age = AgeColumn::use_column(None);
+            let columns = (NameColumn::use_column(None), age.clone());
+            let context = TableContext::use_table_context::<Person>(columns);
+            (age, context.table_data(rows.into()))
+        },
+        |(mut age, data), proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(3));
+                data.go_to_page(2);
+                assert_eq!(collect_indices(data), vec![6]);
+
+                // Only ages >= 23 (indices 3..7) remain: two pages
+                age.filter.set(Some(AgeFilter::MinAge(23)));
+                assert_eq!(data.total_pages(), 2);
+                assert_eq!(data.current_page(), 1);
+                assert_eq!(collect_indices(data), vec![6]);
+
+                // Nothing remains: a single empty page
+                age.filter.set(Some(AgeFilter::MinAge(100)));
+                assert_eq!(data.total_pages(), 1);
+                assert_eq!(data.current_page(), 0);
+                assert_eq!(collect_indices(data), Vec::<usize>::new());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_request_sort_resets_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(7));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(3));
+                data.go_to_page(1);
+                assert_eq!(data.current_page(), 1);
+
+                context
+                    .data
+                    .request_sort(1, SortGesture::AddFirst(descending()));
+                assert_eq!(data.current_page(), 0);
+                assert_eq!(collect_indices(data), vec![6, 5, 4]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_set_page_size_resets_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| numbered_people(7));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(3));
+                data.go_to_page(2);
+
+                data.set_page_size(Some(2));
+                assert_eq!(data.current_page(), 0);
+                assert_eq!(collect_indices(data), vec![0, 1]);
+
+                data.set_page_size(None);
+                assert_eq!(collect_indices(data), vec![0, 1, 2, 3, 4, 5, 6]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
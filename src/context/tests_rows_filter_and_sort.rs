@@ -94,6 +94,14 @@ impl<R: Row + GetRowData<Name>> TableColumn<R> for NameColumn {
         }
     }
 
+    fn is_filter_active(&self) -> bool {
+        self.filter.read().is_some()
+    }
+
+    fn search_text(&self, row: &R) -> Option<String> {
+        Some(row.get().0)
+    }
+
     fn compare(&self, a: &R, b: &R) -> Ordering {
         a.get().0.cmp(&b.get().0)
     }
@@ -141,6 +149,10 @@ impl<R: Row + GetRowData<Age>> TableColumn<R> for AgeColumn {
         }
     }
 
+    fn is_filter_active(&self) -> bool {
+        self.filter.read().is_some()
+    }
+
     fn compare(&self, a: &R, b: &R) -> Ordering {
         a.get().0.cmp(&b.get().0)
     }
@@ -553,3 +565,245 @@ fn test_empty_dataset() {
         |proxy| assert_eq!(proxy.generation, 1),
     );
 }
+
+// D. Memoization
+
+fn sample_people() -> Vec<Person> {
+    vec![
+        Person {
+            name: "Charlie".to_string(),
+            age: 35,
+        },
+        Person {
+            name: "Alice".to_string(),
+            age: 30,
+        },
+        Person {
+            name: "Bob".to_string(),
+            age: 25,
+        },
+    ]
+}
+
+#[test]
+fn test_repeated_rows_calls_reuse_cache() {
+    test_hook(
+        || {
+            let rows = use_signal(sample_people);
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            let data = context.table_data(rows.into());
+            (context, data)
+        },
+        |(_context, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+                assert!(!data.rows_cache_reused());
+
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+                assert!(data.rows_cache_reused());
+            }
+            _ => panic!("Unexpected generation"),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_new_read_signal_for_same_rows_reuses_cache() {
+    test_hook(
+        || {
+            let rows = use_signal(sample_people);
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, rows)
+        },
+        |(context, rows), proxy| match proxy.generation {
+            0 => {
+                // `rows.into()` creates a new ReadSignal wrapper each time
+                let indices: Vec<usize> = context.rows(rows.into()).map(|row| row.index).collect();
+                assert_eq!(indices, vec![0, 1, 2]);
+                assert!(!context.rows_cache_reused());
+
+                let indices: Vec<usize> = context.rows(rows.into()).map(|row| row.index).collect();
+                assert_eq!(indices, vec![0, 1, 2]);
+                assert!(context.rows_cache_reused());
+            }
+            _ => panic!("Unexpected generation"),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_sort_change_invalidates_cache() {
+    test_hook(
+        || {
+            let rows = use_signal(sample_people);
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            let data = context.table_data(rows.into());
+            (context, data)
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+
+                context
+                    .data
+                    .request_sort(1, SortGesture::AddFirst(ascending()));
+
+                assert_eq!(collect_indices(data), vec![2, 1, 0]);
+                assert!(!data.rows_cache_reused());
+
+                assert_eq!(collect_indices(data), vec![2, 1, 0]);
+                assert!(data.rows_cache_reused());
+            }
+            1 => {
+                // Rerender caused by the sort change; the memo is already up to date
+                assert_eq!(collect_indices(data), vec![2, 1, 0]);
+                assert!(data.rows_cache_reused());
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_filter_change_invalidates_cache() {
+    test_hook(
+        || {
+            let rows = use_signal(sample_people);
+            let name = NameColumn::use_column(None);
+            let age = AgeColumn::use_column(None);
+            let context = TableContext::use_table_context::<Person>((name.clone(), age.clone()));
+            let data = context.table_data(rows.into());
+            (age, data)
+        },
+        |(mut age, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+
+                age.filter.set(Some(AgeFilter::MinAge(30)));
+
+                assert_eq!(collect_indices(data), vec![0, 1]);
+                assert!(!data.rows_cache_reused());
+            }
+            1 => {
+                assert_eq!(collect_indices(data), vec![0, 1]);
+                assert!(data.rows_cache_reused());
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_rows_change_invalidates_cache() {
+    test_hook(
+        || {
+            let rows = use_signal(sample_people);
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            let data = context.table_data(rows.into());
+            (rows, data)
+        },
+        |(mut rows, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(collect_indices(data), vec![0, 1, 2]);
+
+                rows.write().push(Person {
+                    name: "David".to_string(),
+                    age: 40,
+                });
+
+                assert_eq!(collect_indices(data), vec![0, 1, 2, 3]);
+                assert!(!data.rows_cache_reused());
+            }
+            1 => {
+                assert_eq!(collect_indices(data), vec![0, 1, 2, 3]);
+                assert!(data.rows_cache_reused());
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+// E. Windowed Rows
+
+#[test]
+fn test_rows_in_range_follows_filter_and_sort() {
+    test_hook(
+        || {
+            let rows = use_signal(|| {
+                (0..10)
+                    .map(|i| Person {
+                        name: format!("Person {i}"),
+                        age: 20 + i,
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let columns = (
+                NameColumn::use_column(None),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(22))),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            let data = context.table_data(rows.into());
+            (context, data)
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                context
+                    .data
+                    .request_sort(1, SortGesture::AddFirst(descending()));
+
+                // Filtered and sorted: 9, 8, 7, 6, 5, 4, 3, 2
+                assert_eq!(data.row_count(), 8);
+                let window: Vec<usize> = data.rows_in_range(2..5).map(|row| row.index).collect();
+                assert_eq!(window, vec![7, 6, 5]);
+
+                // Saturated to the available rows
+                let window: Vec<usize> = data.rows_in_range(6..100).map(|row| row.index).collect();
+                assert_eq!(window, vec![3, 2]);
+                assert_eq!(data.rows_in_range(50..100).count(), 0);
+            }
+            _ => panic!("Unexpected generation"),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_rows_in_range_within_current_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| {
+                (0..10)
+                    .map(|i| Person {
+                        name: format!("Person {i}"),
+                        age: 20 + i,
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(4));
+                data.go_to_page(2);
+
+                // The last page holds rows 8 and 9
+                assert_eq!(data.row_count(), 2);
+                let window: Vec<usize> = data.rows_in_range(0..10).map(|row| row.index).collect();
+                assert_eq!(window, vec![8, 9]);
+            }
+            _ => panic!("Unexpected generation"),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
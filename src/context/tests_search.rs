@@ -0,0 +1,134 @@
+//! Tests for the table-wide search on top of the filter/sort pipeline
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::{AgeColumn, AgeFilter, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+
+const PEOPLE: &[(&str, u32)] = &[("Alice", 30), ("Zoë", 25), ("Khalil", 40), ("Bob", 35)];
+
+fn names<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<String> {
+    data.rows()
+        .map(|row| row.data().read().name.clone())
+        .collect()
+}
+
+#[test]
+fn test_search_query() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_search_query("ALI");
+                assert_eq!(data.search_query(), "ALI");
+                assert_eq!(names(data), ["Alice", "Khalil"]);
+
+                // Diacritics are ignored by default
+                data.set_search_query("zoe");
+                assert_eq!(names(data), ["Zoë"]);
+
+                data.set_search_options(SearchOptions {
+                    diacritic_sensitive: true,
+                    ..Default::default()
+                });
+                assert!(names(data).is_empty());
+
+                data.clear_search();
+                assert_eq!(names(data).len(), 4);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_search_combined_with_filters() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (
+                NameColumn::use_column(None),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(35))),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // Alice matches the search but is filtered out by age
+                data.set_search_query("al");
+                assert_eq!(names(data), ["Khalil"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_search_ignores_hidden_columns() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                data.set_search_query("bob");
+                assert_eq!(names(data), ["Bob"]);
+
+                context.data.hide_column(0);
+                assert!(names(data).is_empty());
+
+                context.data.show_column(0, None);
+                assert_eq!(names(data), ["Bob"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_search_resets_page() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_page_size(Some(1));
+                data.go_to_page(2);
+                assert_eq!(data.current_page(), 2);
+
+                data.set_search_query("a");
+                assert_eq!(data.current_page(), 0);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
@@ -0,0 +1,188 @@
+//! Tests for row selection on top of the filter/sort pipeline
+
+use super::tests_fixtures::{people_named, row};
+use super::tests_rows_filter_and_sort::{AgeColumn, AgeFilter, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+
+const NAMES: &[&str] = &["Dave", "Alice", "Carol", "Bob", "Eve"];
+
+fn selected_names<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<String> {
+    data.selected_rows()
+        .map(|row| row.data().read().name.clone())
+        .collect()
+}
+
+fn ascending() -> Sort {
+    Sort {
+        direction: SortDirection::Ascending,
+    }
+}
+
+#[test]
+fn test_toggle_selected() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                let alice = row(data, "Alice");
+                assert!(!alice.is_selected());
+
+                alice.toggle_selected();
+                row(data, "Eve").toggle_selected();
+                assert!(alice.is_selected());
+                assert_eq!(selected_names(data), ["Alice", "Eve"]);
+
+                alice.toggle_selected();
+                assert!(!alice.is_selected());
+                assert_eq!(data.selected_keys(), ["Eve_24"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_single_mode() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                data.set_selection_mode(SelectionMode::Single);
+                assert_eq!(data.selection_mode(), SelectionMode::Single);
+
+                row(data, "Alice").toggle_selected();
+                row(data, "Bob").toggle_selected();
+                assert_eq!(selected_names(data), ["Bob"]);
+
+                data.select_all_visible();
+                assert_eq!(selected_names(data), ["Bob"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_range_uses_sorted_order() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                // Sorted by name: Alice, Bob, Carol, Dave, Eve
+                context
+                    .data
+                    .request_sort(0, SortGesture::AddFirst(ascending()));
+
+                row(data, "Bob").select(SelectionGesture::Replace);
+                row(data, "Dave").select(SelectionGesture::Range);
+                assert_eq!(selected_names(data), ["Bob", "Carol", "Dave"]);
+
+                // Selection survives a re-sort (by age: Dave, Alice, Carol, Bob, Eve)
+                context
+                    .data
+                    .request_sort(1, SortGesture::AddFirst(ascending()));
+                assert_eq!(selected_names(data), ["Dave", "Carol", "Bob"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_select_all_visible_and_invert() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let columns = (
+                NameColumn::use_column(None),
+                AgeColumn::use_column(Some(AgeFilter::MinAge(22))),
+            );
+            let context = TableContext::use_table_context::<Person>(columns);
+            context.table_data(rows.into())
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // Only Carol, Bob and Eve pass the filter; page size 2 shows Carol and Bob
+                data.set_page_size(Some(2));
+                data.select_all_visible();
+                assert_eq!(selected_names(data), ["Carol", "Bob"]);
+
+                data.next_page();
+                data.invert_selection();
+                assert_eq!(selected_names(data), ["Carol", "Bob", "Eve"]);
+
+                data.prev_page();
+                data.invert_selection();
+                assert_eq!(selected_names(data), ["Eve"]);
+
+                data.clear_selection();
+                assert!(data.selected_keys().is_empty());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_prune_removed_rows() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people_named(NAMES));
+            let age = AgeColumn::use_column(None);
+            let columns = (NameColumn::use_column(None), age.clone());
+            let context = TableContext::use_table_context::<Person>(columns);
+            (rows, age, context.table_data(rows.into()))
+        },
+        |(mut rows, mut age, data), proxy| match proxy.generation {
+            0 => {
+                row(data, "Alice").toggle_selected();
+                row(data, "Bob").toggle_selected();
+
+                // Filtering Alice out keeps her selected, removing Bob prunes him
+                age.filter.set(Some(AgeFilter::MinAge(22)));
+                rows.write().retain(|person| person.name != "Bob");
+                data.prune_removed_rows();
+
+                assert_eq!(data.selected_keys(), ["Alice_21"]);
+                assert!(selected_names(data).is_empty());
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
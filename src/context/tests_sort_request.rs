@@ -18,17 +18,7 @@ fn descending() -> Sort {
 #[test]
 fn test_cancel_on_empty_list() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Cancel on empty list
@@ -46,17 +36,7 @@ fn test_cancel_on_empty_list() {
 #[test]
 fn test_cancel_removes_existing_sort() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add a sort on column 0
@@ -83,17 +63,7 @@ fn test_cancel_removes_existing_sort() {
 #[test]
 fn test_cancel_on_column_without_sort() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add a sort on column 1
@@ -122,17 +92,7 @@ fn test_cancel_on_column_without_sort() {
 #[test]
 fn test_cancel_preserves_other_column_sorts() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add sorts on columns 0, 1, 2
@@ -164,17 +124,7 @@ fn test_cancel_preserves_other_column_sorts() {
 #[test]
 fn test_add_first_ascending_on_empty_list() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Add first sort with ascending direction
@@ -195,17 +145,7 @@ fn test_add_first_ascending_on_empty_list() {
 #[test]
 fn test_add_first_replaces_existing_sort_on_same_column() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add ascending sort on column 0
@@ -235,17 +175,7 @@ fn test_add_first_replaces_existing_sort_on_same_column() {
 #[test]
 fn test_add_first_with_multiple_columns_sorted() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add sorts on columns 1 and 2
@@ -271,17 +201,7 @@ fn test_add_first_with_multiple_columns_sorted() {
 #[test]
 fn test_add_first_moves_column_from_last_to_first() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add sorts on columns 0, 1, 2
@@ -309,17 +229,7 @@ fn test_add_first_moves_column_from_last_to_first() {
 #[test]
 fn test_add_last_ascending_on_empty_list() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Add last sort with ascending direction on empty list
@@ -340,17 +250,7 @@ fn test_add_last_ascending_on_empty_list() {
 #[test]
 fn test_add_last_replaces_existing_sort_on_same_column() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add ascending sort on column 0
@@ -380,17 +280,7 @@ fn test_add_last_replaces_existing_sort_on_same_column() {
 #[test]
 fn test_add_last_with_multiple_columns_sorted() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add sorts on columns 0 and 1
@@ -417,17 +307,7 @@ fn test_add_last_with_multiple_columns_sorted() {
 #[test]
 fn test_add_last_moves_column_from_first_to_last() {
     test_hook(
-        || TableContextData {
-            sorts: use_signal(Vec::new),
-            column_names: use_signal(|| {
-                vec![
-                    "Column 0".to_string(),
-                    "Column 1".to_string(),
-                    "Column 2".to_string(),
-                ]
-            }),
-            column_order: use_signal(|| ColumnOrder::new(3)),
-        },
+        || TableContextData::use_for_test(3),
         |context, proxy| match proxy.generation {
             0 => {
                 // Setup: Add sorts on columns 0, 1, 2
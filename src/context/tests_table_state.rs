@@ -0,0 +1,270 @@
+//! Tests for capturing and restoring the table view state
+
+use super::tests_fixtures::people;
+use super::tests_rows_filter_and_sort::{AgeColumn, NameColumn, Person};
+use super::*;
+use crate::test_suite::test_hook;
+use crate::{MemoryStorage, TableStorage, use_tabular_with_storage};
+
+const PEOPLE: &[(&str, u32)] = &[("Carol", 30), ("Alice", 40), ("Bob", 30)];
+
+fn names<C: Columns<Person>>(data: TableData<C, Person>) -> Vec<String> {
+    data.rows()
+        .map(|row| row.data().read().name.clone())
+        .collect()
+}
+
+fn sort(direction: SortDirection) -> SortGesture {
+    SortGesture::AddLast(Sort { direction })
+}
+
+#[test]
+fn test_snapshot_and_restore() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                context
+                    .data
+                    .request_sort(1, sort(SortDirection::Descending));
+                context.data.request_sort(0, sort(SortDirection::Ascending));
+                context.data.swap_columns(0, 1);
+                let state = context.snapshot();
+                assert_eq!(state.order, ["age", "name"]);
+                assert!(state.hidden.is_empty());
+                assert_eq!(names(data), ["Alice", "Bob", "Carol"]);
+
+                // Change everything, then go back to the saved state
+                context.data.request_sort(0, SortGesture::Cancel);
+                context.data.request_sort(1, SortGesture::Cancel);
+                context.data.reset_column_order();
+                context.data.hide_column(1);
+
+                context.restore(&state);
+                assert_eq!(context.snapshot(), state);
+                assert_eq!(context.data.get_column_order(), [1, 0]);
+                assert_eq!(names(data), ["Alice", "Bob", "Carol"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_restore_with_changed_columns() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                // Saved when the table had an "email" column and no "age" column
+                let state = TableState {
+                    sorts: vec![
+                        SortState {
+                            column: "email".to_string(),
+                            direction: SortDirection::Ascending,
+                        },
+                        SortState {
+                            column: "name".to_string(),
+                            direction: SortDirection::Descending,
+                        },
+                    ],
+                    order: vec!["email".to_string(), "name".to_string()],
+                    hidden: vec![],
+                    ..Default::default()
+                };
+
+                context.restore(&state);
+                assert_eq!(names(data), ["Carol", "Bob", "Alice"]);
+                // The new "age" column is shown after the saved ones
+                assert_eq!(context.data.get_column_order(), [0, 1]);
+                assert_eq!(
+                    context.data.column_context(0).sort_info().unwrap().priority,
+                    0
+                );
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[test]
+fn test_query_string() {
+    test_hook(
+        || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let context = TableContext::use_table_context::<Person>(columns);
+            (context, context.table_data(rows.into()))
+        },
+        |(context, data), proxy| match proxy.generation {
+            0 => {
+                assert_eq!(context.to_query_string(), "");
+
+                context
+                    .data
+                    .request_sort(1, sort(SortDirection::Descending));
+                context.data.request_sort(0, sort(SortDirection::Ascending));
+                assert_eq!(context.to_query_string(), "sort=-age,name");
+
+                context.data.hide_column(0);
+                assert_eq!(context.to_query_string(), "sort=-age,name&cols=age");
+
+                // A shared link with an unknown column and a foreign parameter
+                context.apply_query_string("?tab=people&sort=name,-email&cols=email,name,age");
+                assert_eq!(context.to_query_string(), "sort=name");
+                assert_eq!(names(data), ["Alice", "Bob", "Carol"]);
+
+                context.apply_query_string("cols=age");
+                assert_eq!(context.data.get_column_order(), [1]);
+                assert_eq!(names(data), ["Carol", "Alice", "Bob"]);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        |proxy| assert_eq!(proxy.generation, 1),
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_roundtrip() {
+    let state = TableState {
+        sorts: vec![SortState {
+            column: "age".to_string(),
+            direction: SortDirection::Descending,
+        }],
+        order: vec!["age".to_string()],
+        hidden: vec!["name".to_string()],
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&state).unwrap();
+    assert_eq!(
+        json,
+        r#"{"sorts":[{"column":"age","direction":"Descending"}],"order":["age"],"hidden":["name"]}"#
+    );
+    assert_eq!(serde_json::from_str::<TableState>(&json).unwrap(), state);
+
+    // Missing fields fall back to their defaults
+    let partial: TableState = serde_json::from_str(r#"{"order":["name"]}"#).unwrap();
+    assert_eq!(partial.order, ["name"]);
+    assert!(partial.sorts.is_empty());
+}
+
+#[test]
+fn test_use_tabular_with_storage() {
+    let storage = MemoryStorage::new();
+    storage.save(
+        "people",
+        &TableState {
+            sorts: vec![SortState {
+                column: "age".to_string(),
+                direction: SortDirection::Descending,
+            }],
+            order: vec!["age".to_string(), "name".to_string()],
+            hidden: vec![],
+            ..Default::default()
+        },
+    );
+    let saved = storage.clone();
+    test_hook(
+        move || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            use_tabular_with_storage(columns, rows.into(), storage.clone(), "people")
+        },
+        |data, proxy| match proxy.generation {
+            0 => {
+                // Hydrated from the storage
+                assert_eq!(data.context.data.get_column_order(), [1, 0]);
+                assert_eq!(names(data), ["Alice", "Carol", "Bob"]);
+
+                data.context.data.hide_column(1);
+            }
+            1 => {
+                // Rerender after signal changes - no action needed
+            }
+            _ => panic!("Unexpected generation: {}", proxy.generation),
+        },
+        move |proxy| {
+            assert_eq!(proxy.generation, 1);
+            // Written back after the change
+            let state = saved.load("people").unwrap();
+            assert_eq!(state.order, ["name"]);
+            assert_eq!(state.hidden, ["age"]);
+            assert_eq!(state.sorts.len(), 1);
+        },
+    );
+}
+
+/// Counts the layouts saved into a [`MemoryStorage`]
+#[derive(Clone, Default)]
+struct CountingStorage {
+    inner: MemoryStorage,
+    saves: Rc<std::cell::Cell<usize>>,
+}
+
+impl TableStorage for CountingStorage {
+    fn load(&self, table_id: &str) -> Option<TableState> {
+        self.inner.load(table_id)
+    }
+
+    fn save(&self, table_id: &str, state: &TableState) {
+        self.saves.set(self.saves.get() + 1);
+        self.inner.save(table_id, state);
+    }
+}
+
+#[test]
+fn test_resize_saves_once() {
+    let storage = CountingStorage::default();
+    let saved = storage.clone();
+    test_hook(
+        move || {
+            let rows = use_signal(|| people(PEOPLE));
+            let columns = (NameColumn::use_column(None), AgeColumn::use_column(None));
+            let data = use_tabular_with_storage(columns, rows.into(), storage.clone(), "people");
+
+            // Drag a column, giving the storage effect a chance to run between pointer moves
+            let saves = storage.saves.clone();
+            let mut step = use_signal(|| 0);
+            use_effect(move || {
+                let column = data.context.data.column_context(0);
+                match step() {
+                    0 => column.begin_resize(),
+                    1..=3 => assert_eq!(saves.get(), 1),
+                    4 => column.end_resize(),
+                    _ => return,
+                }
+                column.set_width(100.0 + step() as f64);
+                *step.write() += 1;
+            });
+        },
+        |_, _| {},
+        move |_| {
+            // Saved after mounting and once the drag is over
+            assert_eq!(saved.saves.get(), 2);
+            let state = saved.load("people").unwrap();
+            assert_eq!(state.widths, [("name".to_string(), 104.0)].into());
+        },
+    );
+}
@@ -0,0 +1,368 @@
+use crate::{ColumnContext, Columns, Row, TableColumn};
+#[cfg(feature = "export")]
+use crate::{
+    Exporter, SerializableCell, SerializableColumn, SerializableColumns, SerializableHeader,
+};
+use dioxus::prelude::*;
+use std::any::Any;
+
+/// Object-safe version of [`TableColumn`], for tables whose columns are only known at runtime.
+///
+/// Implemented for every [`TableColumn`], so any column can be boxed as a
+/// `Box<dyn DynTableColumn<R>>`. [`Columns`] is implemented for a `Vec` of them, so a table can
+/// have a variable number of heterogeneous columns, e.g. built from a schema fetched at runtime.
+/// Such columns sort, filter, reorder and resize through [`ColumnContext`] like the columns of
+/// a tuple; their index is their position in the `Vec`. Passing a different `Vec` on a later
+/// render switches the table to the new columns (see [`use_tabular`](crate::use_tabular)).
+///
+/// The methods are prefixed with `dyn_` so they don't clash with those of [`TableColumn`], and
+/// are not meant to be called directly. Two boxed columns are equal if they have the same type
+/// and are equal as that type.
+///
+/// For export, box the columns as `DynSerializableColumn` instead (requires the `export`
+/// feature).
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Record { id: u32, fields: Vec<String> }
+/// # impl Row for Record {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// /// A column showing one field of a runtime schema
+/// #[derive(Clone, PartialEq)]
+/// struct FieldColumn {
+///     index: usize,
+///     label: String,
+/// }
+///
+/// impl TableColumn<Record> for FieldColumn {
+///     fn column_name(&self) -> String {
+///         self.label.clone()
+///     }
+///
+///     fn render_header(&self, _: ColumnContext, attributes: Vec<Attribute>) -> Element {
+///         rsx! { th { ..attributes, "{self.label}" } }
+///     }
+///
+///     fn render_cell(&self, _: ColumnContext, row: &Record, attributes: Vec<Attribute>) -> Element {
+///         rsx! { td { ..attributes, "{row.fields[self.index]}" } }
+///     }
+/// }
+///
+/// # #[derive(Clone, PartialEq)]
+/// # struct IdColumn;
+/// # impl TableColumn<Record> for IdColumn {
+/// #     fn column_name(&self) -> String { "id".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &Record, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # fn app() -> Element {
+/// #     let records = use_signal(|| vec![Record { id: 1, fields: vec!["a".into()] }]);
+/// let schema = vec!["Name".to_string(), "Email".to_string()];
+/// let mut columns: Vec<Box<dyn DynTableColumn<Record>>> = vec![Box::new(IdColumn)];
+/// for (index, label) in schema.into_iter().enumerate() {
+///     columns.push(Box::new(FieldColumn { index, label }));
+/// }
+/// let data = use_tabular(columns, records.into());
+/// #     rsx! { table {} }
+/// # }
+/// ```
+pub trait DynTableColumn<R: Row>: 'static {
+    /// See [`TableColumn::column_name`].
+    fn dyn_column_name(&self) -> String;
+    /// See [`TableColumn::render_header`].
+    fn dyn_render_header(&self, context: ColumnContext, attributes: Vec<Attribute>) -> Element;
+    /// See [`TableColumn::render_cell`].
+    fn dyn_render_cell(
+        &self,
+        context: ColumnContext,
+        row: &R,
+        attributes: Vec<Attribute>,
+    ) -> Element;
+    /// See [`TableColumn::render_footer`].
+    fn dyn_render_footer(
+        &self,
+        context: ColumnContext,
+        rows: &mut dyn Iterator<Item = &R>,
+        attributes: Vec<Attribute>,
+    ) -> Element;
+    /// See [`TableColumn::filter`].
+    fn dyn_filter(&self, row: &R) -> bool;
+    /// See [`TableColumn::is_filter_active`].
+    fn dyn_is_filter_active(&self) -> bool;
+    /// See [`TableColumn::search_text`].
+    fn dyn_search_text(&self, row: &R) -> Option<String>;
+    /// See [`TableColumn::compare`].
+    fn dyn_compare(&self, a: &R, b: &R) -> std::cmp::Ordering;
+    /// See [`TableColumn::group_key`].
+    fn dyn_group_key(&self, row: &R) -> Option<String>;
+    /// See [`TableColumn::default_width`].
+    fn dyn_default_width(&self) -> Option<f64>;
+    /// See [`TableColumn::min_width`].
+    fn dyn_min_width(&self) -> Option<f64>;
+    /// See [`TableColumn::max_width`].
+    fn dyn_max_width(&self) -> Option<f64>;
+    /// Clones this column into a new box.
+    fn dyn_clone(&self) -> Box<dyn DynTableColumn<R>>;
+    /// Returns whether `other` has the type of this column and is equal to it.
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+    /// Returns this column as [`Any`], for [`dyn_eq`](Self::dyn_eq).
+    fn dyn_as_any(&self) -> &dyn Any;
+}
+
+impl<R: Row, C: TableColumn<R>> DynTableColumn<R> for C {
+    fn dyn_column_name(&self) -> String {
+        self.column_name()
+    }
+    fn dyn_render_header(&self, context: ColumnContext, attributes: Vec<Attribute>) -> Element {
+        self.render_header(context, attributes)
+    }
+    fn dyn_render_cell(
+        &self,
+        context: ColumnContext,
+        row: &R,
+        attributes: Vec<Attribute>,
+    ) -> Element {
+        self.render_cell(context, row, attributes)
+    }
+    fn dyn_render_footer(
+        &self,
+        context: ColumnContext,
+        rows: &mut dyn Iterator<Item = &R>,
+        attributes: Vec<Attribute>,
+    ) -> Element {
+        self.render_footer(context, rows, attributes)
+    }
+    fn dyn_filter(&self, row: &R) -> bool {
+        self.filter(row)
+    }
+    fn dyn_is_filter_active(&self) -> bool {
+        self.is_filter_active()
+    }
+    fn dyn_search_text(&self, row: &R) -> Option<String> {
+        self.search_text(row)
+    }
+    fn dyn_compare(&self, a: &R, b: &R) -> std::cmp::Ordering {
+        self.compare(a, b)
+    }
+    fn dyn_group_key(&self, row: &R) -> Option<String> {
+        self.group_key(row)
+    }
+    fn dyn_default_width(&self) -> Option<f64> {
+        self.default_width()
+    }
+    fn dyn_min_width(&self) -> Option<f64> {
+        self.min_width()
+    }
+    fn dyn_max_width(&self) -> Option<f64> {
+        self.max_width()
+    }
+    fn dyn_clone(&self) -> Box<dyn DynTableColumn<R>> {
+        Box::new(self.clone())
+    }
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<C>() == Some(self)
+    }
+    fn dyn_as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<R: Row> Clone for Box<dyn DynTableColumn<R>> {
+    fn clone(&self) -> Self {
+        self.dyn_clone()
+    }
+}
+
+impl<R: Row> PartialEq for dyn DynTableColumn<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.dyn_as_any())
+    }
+}
+
+/// Object-safe version of [`SerializableColumn`], for exporting tables with columns only known
+/// at runtime.
+///
+/// Implemented for every [`SerializableColumn`]. Box columns as
+/// `Box<dyn DynSerializableColumn<R>>` and put them in a `Vec` to get [`SerializableColumns`],
+/// like with [`DynTableColumn`].
+///
+/// Cells are passed to the exporter as an [`erased_serde::Serialize`] trait object, so they
+/// export exactly like the same column in a tuple.
+#[cfg(feature = "export")]
+pub trait DynSerializableColumn<R: Row>: DynTableColumn<R> {
+    /// See [`SerializableColumn::header`].
+    fn dyn_header(&self) -> String;
+    /// Returns the serializable cell of the given row. See
+    /// [`SerializableColumn::serialize_cell`].
+    fn dyn_serialize_cell<'a>(&'a self, row: &'a R) -> Box<dyn erased_serde::Serialize + 'a>;
+    /// See [`SerializableColumn::include_in_export`].
+    fn dyn_include_in_export(&self) -> bool;
+    /// Clones this column into a new box.
+    fn dyn_clone_serializable(&self) -> Box<dyn DynSerializableColumn<R>>;
+}
+
+#[cfg(feature = "export")]
+impl<R: Row, C: SerializableColumn<R>> DynSerializableColumn<R> for C {
+    fn dyn_header(&self) -> String {
+        self.header()
+    }
+    fn dyn_serialize_cell<'a>(&'a self, row: &'a R) -> Box<dyn erased_serde::Serialize + 'a> {
+        Box::new(self.serialize_cell(row))
+    }
+    fn dyn_include_in_export(&self) -> bool {
+        self.include_in_export()
+    }
+    fn dyn_clone_serializable(&self) -> Box<dyn DynSerializableColumn<R>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(feature = "export")]
+impl<R: Row> Clone for Box<dyn DynSerializableColumn<R>> {
+    fn clone(&self) -> Self {
+        self.dyn_clone_serializable()
+    }
+}
+
+#[cfg(feature = "export")]
+impl<R: Row> PartialEq for dyn DynSerializableColumn<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.dyn_as_any())
+    }
+}
+
+macro_rules! dyn_columns {
+    ($column:ident) => {
+        impl<R: Row> Columns<R> for Vec<Box<dyn $column<R>>> {
+            fn column_names(&self) -> Vec<String> {
+                self.iter().map(|column| column.dyn_column_name()).collect()
+            }
+            fn headers(&self) -> Vec<Box<dyn Fn(ColumnContext, Vec<Attribute>) -> Element + '_>> {
+                self.iter()
+                    .map(
+                        |column| -> Box<dyn Fn(ColumnContext, Vec<Attribute>) -> Element + '_> {
+                            Box::new(move |context, attributes| {
+                                column.dyn_render_header(context, attributes)
+                            })
+                        },
+                    )
+                    .collect()
+            }
+            fn footers(
+                &self,
+            ) -> Vec<
+                Box<
+                    dyn Fn(ColumnContext, &mut dyn Iterator<Item = &R>, Vec<Attribute>) -> Element
+                        + '_,
+                >,
+            > {
+                self.iter()
+                    .map(
+                        |column| -> Box<
+                            dyn Fn(
+                                    ColumnContext,
+                                    &mut dyn Iterator<Item = &R>,
+                                    Vec<Attribute>,
+                                ) -> Element
+                                + '_,
+                        > {
+                            Box::new(move |context, rows, attributes| {
+                                column.dyn_render_footer(context, rows, attributes)
+                            })
+                        },
+                    )
+                    .collect()
+            }
+            fn columns(
+                &self,
+            ) -> Vec<Box<dyn Fn(ColumnContext, &R, Vec<Attribute>) -> Element + '_>> {
+                self.iter()
+                    .map(
+                        |column| -> Box<dyn Fn(ColumnContext, &R, Vec<Attribute>) -> Element + '_> {
+                            Box::new(move |context, row, attributes| {
+                                column.dyn_render_cell(context, row, attributes)
+                            })
+                        },
+                    )
+                    .collect()
+            }
+            fn filter(&self, row: &R) -> bool {
+                self.iter().all(|column| column.dyn_filter(row))
+            }
+            fn filters(&self) -> Vec<Option<Box<dyn Fn(&R) -> bool + '_>>> {
+                self.iter()
+                    .map(|column| {
+                        column
+                            .dyn_is_filter_active()
+                            .then(|| -> Box<dyn Fn(&R) -> bool + '_> {
+                                Box::new(move |row| column.dyn_filter(row))
+                            })
+                    })
+                    .collect()
+            }
+            fn search_text(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                self.iter()
+                    .map(|column| -> Box<dyn Fn(&R) -> Option<String> + '_> {
+                        Box::new(move |row| column.dyn_search_text(row))
+                    })
+                    .collect()
+            }
+            fn compare(&self) -> Vec<Box<dyn Fn(&R, &R) -> std::cmp::Ordering + '_>> {
+                self.iter()
+                    .map(|column| -> Box<dyn Fn(&R, &R) -> std::cmp::Ordering + '_> {
+                        Box::new(move |a, b| column.dyn_compare(a, b))
+                    })
+                    .collect()
+            }
+            fn group_key(&self) -> Vec<Box<dyn Fn(&R) -> Option<String> + '_>> {
+                self.iter()
+                    .map(|column| -> Box<dyn Fn(&R) -> Option<String> + '_> {
+                        Box::new(move |row| column.dyn_group_key(row))
+                    })
+                    .collect()
+            }
+            fn default_widths(&self) -> Vec<Option<f64>> {
+                self.iter()
+                    .map(|column| column.dyn_default_width())
+                    .collect()
+            }
+            fn min_widths(&self) -> Vec<Option<f64>> {
+                self.iter().map(|column| column.dyn_min_width()).collect()
+            }
+            fn max_widths(&self) -> Vec<Option<f64>> {
+                self.iter().map(|column| column.dyn_max_width()).collect()
+            }
+        }
+    };
+}
+
+dyn_columns!(DynTableColumn);
+#[cfg(feature = "export")]
+dyn_columns!(DynSerializableColumn);
+
+#[cfg(feature = "export")]
+impl<R: Row> SerializableColumns<R> for Vec<Box<dyn DynSerializableColumn<R>>> {
+    fn serialize_headers(&self) -> Vec<SerializableHeader<'_>> {
+        self.iter()
+            .map(|column| SerializableHeader {
+                header_fn: Box::new(move || column.dyn_header()),
+                include_in_export: column.dyn_include_in_export(),
+            })
+            .collect()
+    }
+    fn serialize_cell<E: Exporter>(&self) -> Vec<SerializableCell<'_, R, E>> {
+        self.iter()
+            .map(|column| SerializableCell {
+                cell_fn: Box::new(move |row_index, col_index, row, exporter: &mut E| {
+                    exporter.serialize_cell(row_index, col_index, column.dyn_serialize_cell(row))
+                }),
+                include_in_export: column.dyn_include_in_export(),
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,32 @@
 use crate::{Columns, Row, SerializableColumns, TableColumn, TableContext, TableData};
 use dioxus::prelude::*;
 use serde::Serialize;
+use std::collections::HashMap;
+
+mod chunked;
+mod csv;
+mod error;
+mod grid;
+mod html;
+mod json;
+mod markdown;
+mod options;
+mod value;
+#[cfg(feature = "xlsx")]
+mod xlsx;
+#[cfg(feature = "xlsx")]
+mod zip;
+
+pub use chunked::*;
+pub use csv::*;
+pub use error::*;
+pub use html::*;
+pub use json::*;
+pub use markdown::*;
+pub use options::*;
+pub use value::CellError;
+#[cfg(feature = "xlsx")]
+pub use xlsx::*;
 
 /// A column that can be serialized to various export formats.
 ///
@@ -72,22 +98,36 @@ pub trait SerializableColumn<R: Row>: TableColumn<R> {
 
 /// Trait for exporting table data to various formats.
 ///
-/// Implement this trait to create custom export formats (CSV, Excel, JSON, etc.).
+/// [`CsvExporter`], [`JsonExporter`], [`MarkdownExporter`], [`HtmlExporter`] and, with the `xlsx`
+/// feature, `XlsxExporter` are provided. Implement this trait to create other export formats
+/// (XML, Parquet, etc.).
+///
+/// [`TableContext::serialize`] drives an exporter with the following calls:
+///
+/// 1. [`begin_table`](Self::begin_table) with the number of exported columns
+/// 2. [`serialize_header`](Self::serialize_header) for each column
+/// 3. [`end_headers`](Self::end_headers)
+/// 4. For each row: [`begin_row`](Self::begin_row), [`serialize_cell`](Self::serialize_cell)
+///    for each column, and [`end_row`](Self::end_row)
+/// 5. [`finish`](Self::finish)
+///
+/// Only `serialize_header` and `serialize_cell` are required; the other callbacks do nothing
+/// by default.
 ///
 /// # Example
 ///
 /// ```
 /// # use dioxus_tabular::Exporter;
 /// # use serde::Serialize;
-/// struct CsvExporter {
+/// struct TsvExporter {
 ///     output: String,
 /// }
 ///
-/// impl Exporter for CsvExporter {
+/// impl Exporter for TsvExporter {
 ///     type Error = std::fmt::Error;
 ///
 ///     fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
-///         // Write header to CSV
+///         // Write header to TSV
 ///         Ok(())
 ///     }
 ///
@@ -97,7 +137,12 @@ pub trait SerializableColumn<R: Row>: TableColumn<R> {
 ///         col: usize,
 ///         cell: impl Serialize + 'a,
 ///     ) -> Result<(), Self::Error> {
-///         // Write cell to CSV
+///         // Write cell to TSV
+///         Ok(())
+///     }
+///
+///     fn end_row(&mut self, row: usize) -> Result<(), Self::Error> {
+///         self.output.push('\n');
 ///         Ok(())
 ///     }
 /// }
@@ -106,6 +151,16 @@ pub trait Exporter {
     /// The error type returned by export operations.
     type Error;
 
+    /// Called before anything else is exported.
+    ///
+    /// # Parameters
+    ///
+    /// - `columns`: The number of exported columns
+    fn begin_table(&mut self, columns: usize) -> Result<(), Self::Error> {
+        let _ = columns;
+        Ok(())
+    }
+
     /// Serializes a column header.
     ///
     /// # Parameters
@@ -114,6 +169,22 @@ pub trait Exporter {
     /// - `header`: The header text
     fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error>;
 
+    /// Called after all headers were serialized, even if there are no columns.
+    fn end_headers(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called before the cells of a row are serialized.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The row index
+    /// - `key`: The [`Row::key`] of the row
+    fn begin_row(&mut self, row: usize, key: &str) -> Result<(), Self::Error> {
+        let _ = (row, key);
+        Ok(())
+    }
+
     /// Serializes a table cell.
     ///
     /// # Parameters
@@ -127,55 +198,304 @@ pub trait Exporter {
         col: usize,
         cell: impl Serialize + 'a,
     ) -> Result<(), Self::Error>;
+
+    /// Called after all cells of a row were serialized.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The row index
+    fn end_row(&mut self, row: usize) -> Result<(), Self::Error> {
+        let _ = row;
+        Ok(())
+    }
+
+    /// Called after everything was exported, to complete the output.
+    fn finish(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 impl<C: Columns<R> + SerializableColumns<R>, R: Row> TableData<C, R> {
-    pub fn serialize<E: Exporter>(&self, exporter: &mut E) -> Result<(), E::Error> {
+    /// Exports the visible columns and the filtered rows, as displayed.
+    pub fn serialize<E: Exporter>(&self, exporter: &mut E) -> Result<(), ExportError<E::Error>> {
         self.context.serialize(self.rows, exporter)
     }
+
+    /// Exports the columns and rows chosen by `options`.
+    pub fn serialize_with_options<E: Exporter>(
+        &self,
+        options: &ExportOptions,
+        exporter: &mut E,
+    ) -> Result<(), ExportError<E::Error>> {
+        self.context
+            .serialize_with_options(self.rows, options, exporter)
+    }
+
+    /// Exports the columns and rows chosen by `options` in chunks, without blocking the UI.
+    ///
+    /// See [`TableContext::serialize_chunked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_tabular::*;
+    /// # use serde::Serialize;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct User { id: u32 }
+    /// # impl Row for User {
+    /// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+    /// # }
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Col;
+    /// # impl TableColumn<User> for Col {
+    /// #     fn column_name(&self) -> String { "col".into() }
+    /// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+    /// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+    /// # }
+    /// # impl SerializableColumn<User> for Col {
+    /// #     fn serialize_cell(&self, row: &User) -> impl Serialize + '_ { row.id }
+    /// # }
+    /// # fn start(data: TableData<(Col,), User>, mut progress: Signal<ExportProgress>) -> CancelToken {
+    /// let export = ChunkedExport::new().with_progress(move |p| progress.set(p));
+    /// let cancel = export.cancel_token();
+    /// spawn(async move {
+    ///     let mut exporter = CsvExporter::new(Vec::new());
+    ///     let options = ExportOptions::default();
+    ///     let status = data.serialize_chunked(&options, &mut exporter, export).await;
+    ///     if let Ok(ExportStatus::Completed) = status {
+    ///         let csv = exporter.into_inner();
+    ///         // Save the file
+    ///     }
+    /// });
+    /// // Call `cancel.cancel()` to stop the export
+    /// # cancel
+    /// # }
+    /// ```
+    pub async fn serialize_chunked<E: Exporter>(
+        &self,
+        options: &ExportOptions,
+        exporter: &mut E,
+        chunked: ChunkedExport,
+    ) -> Result<ExportStatus, ExportError<E::Error>> {
+        self.context
+            .serialize_chunked(self.rows, options, exporter, chunked)
+            .await
+    }
 }
 
 impl<C> TableContext<C> {
+    /// Exports the visible columns in display order and the filtered rows in sorted order,
+    /// across all pages.
     pub fn serialize<R, E: Exporter>(
         &self,
         rows: ReadSignal<Vec<R>>,
         exporter: &mut E,
-    ) -> Result<(), E::Error>
+    ) -> Result<(), ExportError<E::Error>>
+    where
+        C: Columns<R> + SerializableColumns<R>,
+        R: Row,
+    {
+        self.serialize_with_options(rows, &ExportOptions::default(), exporter)
+    }
+
+    /// Exports the columns and rows chosen by `options`. See [`ExportOptions`].
+    pub fn serialize_with_options<R, E: Exporter>(
+        &self,
+        rows: ReadSignal<Vec<R>>,
+        options: &ExportOptions,
+        exporter: &mut E,
+    ) -> Result<(), ExportError<E::Error>>
+    where
+        C: Columns<R> + SerializableColumns<R>,
+        R: Row,
+    {
+        let (columns, indices) = self.export_plan(rows, options);
+        self.serialize_headers(&columns, exporter)?;
+        let rows_data = rows.read();
+        let export_rows = indices.iter().map(|&i| &rows_data[i]);
+        self.serialize_rows(export_rows, &columns, 0, exporter)?;
+        exporter.finish().map_err(ExportError::new)
+    }
+
+    /// Exports the columns and rows chosen by `options` in chunks, yielding to the async runtime
+    /// between chunks. See [`ChunkedExport`].
+    ///
+    /// The exported rows and their order are fixed at the start, and each row is then found by
+    /// its key when its chunk is serialized, so rows inserted, removed or moved in the meantime
+    /// don't shift the others. Rows removed while the export is running are skipped, and other
+    /// rows are exported as they are when their chunk is serialized.
+    pub async fn serialize_chunked<R, E: Exporter>(
+        &self,
+        rows: ReadSignal<Vec<R>>,
+        options: &ExportOptions,
+        exporter: &mut E,
+        mut chunked: ChunkedExport,
+    ) -> Result<ExportStatus, ExportError<E::Error>>
+    where
+        C: Columns<R> + SerializableColumns<R>,
+        R: Row,
+    {
+        let (columns, indices) = self.export_plan(rows, options);
+        self.serialize_headers(&columns, exporter)?;
+        // The rows may change between chunks, so remember which row each index refers to
+        let snapshot: Vec<(usize, String)> = {
+            let rows_data = rows.read();
+            indices
+                .into_iter()
+                .map(|i| (i, rows_data[i].key().into()))
+                .collect()
+        };
+
+        let total = snapshot.len();
+        let mut progress = ExportProgress { done: 0, total };
+        chunked.report(progress);
+        let mut row_index = 0;
+        for chunk in snapshot.chunks(chunked.chunk_size) {
+            chunked::yield_now().await;
+            if chunked.cancel.is_cancelled() {
+                return Ok(ExportStatus::Cancelled);
+            }
+            // The rows are not borrowed while reporting progress, so the callback may edit them
+            row_index += {
+                let rows_data = rows.read();
+                // Built on the first row that is no longer at its index
+                let mut positions: Option<HashMap<String, usize>> = None;
+                let export_rows = chunk.iter().filter_map(|(i, key)| {
+                    if let Some(row) = rows_data.get(*i) {
+                        let current: String = row.key().into();
+                        if current == *key {
+                            return Some(row);
+                        }
+                    }
+                    let positions = positions.get_or_insert_with(|| {
+                        rows_data
+                            .iter()
+                            .enumerate()
+                            .map(|(i, row)| (row.key().into(), i))
+                            .collect()
+                    });
+                    positions.get(key).map(|&i| &rows_data[i])
+                });
+                self.serialize_rows(export_rows, &columns, row_index, exporter)?
+            };
+            progress.done += chunk.len();
+            chunked.report(progress);
+        }
+        exporter.finish().map_err(ExportError::new)?;
+        Ok(ExportStatus::Completed)
+    }
+
+    /// Returns the exported columns and the indices of the exported rows, in export order.
+    fn export_plan<R>(
+        &self,
+        rows: ReadSignal<Vec<R>>,
+        options: &ExportOptions,
+    ) -> (Vec<usize>, Vec<usize>)
     where
         C: Columns<R> + SerializableColumns<R>,
         R: Row,
     {
         let binding = self.columns.read();
         let all_headers = binding.serialize_headers();
-        let all_cells = binding.serialize_cell();
 
-        // Use self.headers() which already respects column order and visibility
-        let mut export_col = 0;
-        for header_data in self.headers() {
-            let col_index = header_data.column_index;
-            if all_headers[col_index].include_in_export {
-                exporter.serialize_header(export_col, &(all_headers[col_index].header_fn)())?;
-                export_col += 1;
+        // The visible columns in display order, followed by the hidden ones in definition order
+        let mut columns = self.data.get_column_order();
+        if options.all_columns {
+            columns.extend(
+                (0..self.data.num_columns()).filter(|&col| !self.data.is_column_visible(col)),
+            );
+        }
+        if options.definition_order {
+            columns.sort_unstable();
+        }
+        columns.retain(|&col_index| all_headers[col_index].include_in_export);
+
+        let mut indices = match (options.all_rows, options.original_order) {
+            // The memoized filtered and sorted rows, as displayed
+            (false, false) => self.with_row_indices(rows, |indices| indices.to_vec()),
+            (false, true) => {
+                let mut indices = self.with_row_indices(rows, |indices| indices.to_vec());
+                indices.sort_unstable();
+                indices
+            }
+            (true, false) => {
+                let rows_data = rows.read();
+                self.sort_indices(&rows_data, (0..rows_data.len()).collect())
+                    .indices
             }
+            (true, true) => (0..rows.read().len()).collect(),
+        };
+        if options.row_keys.is_some() {
+            let rows_data = rows.read();
+            indices.retain(|&i| options.includes_row(&rows_data[i].key().into()));
         }
+        (columns, indices)
+    }
+
+    fn serialize_headers<R, E: Exporter>(
+        &self,
+        columns: &[usize],
+        exporter: &mut E,
+    ) -> Result<(), ExportError<E::Error>>
+    where
+        C: Columns<R> + SerializableColumns<R>,
+        R: Row,
+    {
+        let binding = self.columns.read();
+        let all_headers = binding.serialize_headers();
+
+        exporter
+            .begin_table(columns.len())
+            .map_err(ExportError::new)?;
+        for (export_col, &col_index) in columns.iter().enumerate() {
+            let header = (all_headers[col_index].header_fn)();
+            exporter
+                .serialize_header(export_col, &header)
+                .map_err(|source| {
+                    let column_name = self.data.get_column_name(col_index);
+                    ExportError::new(source).with_column(column_name, header)
+                })?;
+        }
+        exporter.end_headers().map_err(ExportError::new)
+    }
 
-        // Use self.rows(rows) which already respects sorting and filtering
-        let sorted_rows: Vec<_> = self.rows(rows).collect();
-        for (row_index, row_data) in sorted_rows.into_iter().enumerate() {
-            // Get the actual row data from the sorted position
-            let row = &row_data.rows.read()[row_data.index];
-            let mut export_col = 0;
+    /// Serializes `rows`, numbering them from `first_row`, and returns the number of rows
+    /// serialized.
+    fn serialize_rows<'r, R, E: Exporter>(
+        &self,
+        rows: impl Iterator<Item = &'r R>,
+        columns: &[usize],
+        first_row: usize,
+        exporter: &mut E,
+    ) -> Result<usize, ExportError<E::Error>>
+    where
+        C: Columns<R> + SerializableColumns<R>,
+        R: Row,
+    {
+        let binding = self.columns.read();
+        let all_headers = binding.serialize_headers();
+        let all_cells = binding.serialize_cell();
 
+        let mut row_index = first_row;
+        for row in rows {
+            let key: String = row.key().into();
+            let row_error = |source| ExportError::new(source).with_row(row_index, key.as_str());
+            exporter.begin_row(row_index, &key).map_err(row_error)?;
             // Use the same column order as headers
-            for cell_data in row_data.cells() {
-                let col_index = cell_data.column_index;
-                if all_cells[col_index].include_in_export {
-                    (all_cells[col_index].cell_fn)(row_index, export_col, row, exporter)?;
-                    export_col += 1;
-                }
+            for (export_col, &col_index) in columns.iter().enumerate() {
+                (all_cells[col_index].cell_fn)(row_index, export_col, row, exporter).map_err(
+                    |source| {
+                        let column_name = self.data.get_column_name(col_index);
+                        let header = (all_headers[col_index].header_fn)();
+                        row_error(source).with_column(column_name, header)
+                    },
+                )?;
             }
+            exporter.end_row(row_index).map_err(row_error)?;
+            row_index += 1;
         }
-        Ok(())
+        Ok(row_index - first_row)
     }
 }
 
@@ -183,7 +503,11 @@ impl<C> TableContext<C> {
 mod tests {
     use super::*;
     use crate::test_suite::{test_hook, test_hook_simple};
-    use crate::{ColumnContext, Sort, SortDirection, SortGesture};
+    use crate::{
+        ColumnContext, DynSerializableColumn, IntoColumns, Sort, SortDirection, SortGesture,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[derive(Debug, Clone, PartialEq)]
     struct Person {
@@ -226,6 +550,9 @@ mod tests {
         fn compare(&self, a: &Person, b: &Person) -> std::cmp::Ordering {
             a.name.cmp(&b.name)
         }
+        fn search_text(&self, row: &Person) -> Option<String> {
+            Some(row.name.clone())
+        }
     }
     impl SerializableColumn<Person> for NameColumn {
         fn serialize_cell(&self, row: &Person) -> impl Serialize + '_ {
@@ -298,6 +625,7 @@ mod tests {
     struct MockExporter {
         headers: Vec<(usize, String)>,
         cells: Vec<(usize, usize, String)>,
+        events: Vec<String>,
     }
 
     impl MockExporter {
@@ -305,6 +633,7 @@ mod tests {
             Self {
                 headers: Vec::new(),
                 cells: Vec::new(),
+                events: Vec::new(),
             }
         }
     }
@@ -312,8 +641,24 @@ mod tests {
     impl Exporter for MockExporter {
         type Error = ();
 
+        fn begin_table(&mut self, columns: usize) -> Result<(), Self::Error> {
+            self.events.push(format!("begin_table {columns}"));
+            Ok(())
+        }
+
         fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
             self.headers.push((col, header.to_string()));
+            self.events.push(format!("header {col}"));
+            Ok(())
+        }
+
+        fn end_headers(&mut self) -> Result<(), Self::Error> {
+            self.events.push("end_headers".to_string());
+            Ok(())
+        }
+
+        fn begin_row(&mut self, row: usize, key: &str) -> Result<(), Self::Error> {
+            self.events.push(format!("begin_row {row} {key}"));
             Ok(())
         }
 
@@ -325,10 +670,100 @@ mod tests {
         ) -> Result<(), Self::Error> {
             let json = serde_json::to_string(&cell).unwrap();
             self.cells.push((row, col, json));
+            self.events.push(format!("cell {row} {col}"));
+            Ok(())
+        }
+
+        fn end_row(&mut self, row: usize) -> Result<(), Self::Error> {
+            self.events.push(format!("end_row {row}"));
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<(), Self::Error> {
+            self.events.push("finish".to_string());
             Ok(())
         }
     }
 
+    #[test]
+    fn test_export_lifecycle() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(vec![
+                    Person {
+                        name: "Alice".to_string(),
+                        age: 30,
+                    },
+                    Person {
+                        name: "Bob".to_string(),
+                        age: 25,
+                    },
+                ]);
+                context.data.request_sort(
+                    1,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+
+                let mut exporter = MockExporter::new();
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                exporter.events
+            },
+            |events| {
+                assert_eq!(
+                    events,
+                    [
+                        "begin_table 2",
+                        "header 0",
+                        "header 1",
+                        "end_headers",
+                        "begin_row 0 Bob_25",
+                        "cell 0 0",
+                        "cell 0 1",
+                        "end_row 0",
+                        "begin_row 1 Alice_30",
+                        "cell 1 0",
+                        "cell 1 1",
+                        "end_row 1",
+                        "finish",
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_export_lifecycle_without_columns() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn,));
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                context.data.hide_column(0);
+
+                let mut exporter = MockExporter::new();
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                exporter.events
+            },
+            |events| {
+                assert_eq!(
+                    events,
+                    [
+                        "begin_table 0",
+                        "end_headers",
+                        "begin_row 0 Alice_30",
+                        "end_row 0",
+                        "finish",
+                    ]
+                );
+            },
+        );
+    }
+
     #[test]
     fn test_export_empty_table() {
         test_hook_simple(
@@ -466,6 +901,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_export_with_nested_columns() {
+        test_hook(
+            || {
+                let columns = ((NameColumn,), (AgeColumn, PriorityColumn));
+                let context = TableContext::use_table_context(columns.into_columns());
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                (context, rows)
+            },
+            |(context, rows), _| {
+                context.data.hide_column(2);
+
+                let mut exporter = MockExporter::new();
+                context.serialize(rows.into(), &mut exporter).unwrap();
+
+                // The sets are exported as one flat table
+                assert_eq!(
+                    exporter.headers.as_slice(),
+                    &[(0, "Name".to_string()), (1, "Age".to_string())]
+                );
+                assert_eq!(
+                    exporter.cells.as_slice(),
+                    &[(0, 0, "\"Alice\"".to_string()), (0, 1, "30".to_string())]
+                );
+            },
+            |_| {},
+        );
+    }
+
+    #[test]
+    fn test_export_with_dyn_columns() {
+        test_hook(
+            || {
+                let columns: Vec<Box<dyn DynSerializableColumn<Person>>> =
+                    vec![Box::new(AgeColumn), Box::new(NameColumn)];
+                let context = TableContext::use_table_context(columns);
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                (context, rows)
+            },
+            |(context, rows), _| {
+                context.data.swap_columns(0, 1);
+
+                let mut exporter = MockExporter::new();
+                context.serialize(rows.into(), &mut exporter).unwrap();
+
+                assert_eq!(
+                    exporter.headers.as_slice(),
+                    &[(0, "Name".to_string()), (1, "Age".to_string())]
+                );
+                assert_eq!(
+                    exporter.cells.as_slice(),
+                    &[(0, 0, "\"Alice\"".to_string()), (0, 1, "30".to_string())]
+                );
+            },
+            |_| {},
+        );
+    }
+
+    #[derive(Serialize)]
+    enum Measure {
+        Ratio(f64),
+    }
+
+    #[derive(Serialize)]
+    struct Detail {
+        zeta: i128,
+        alpha: Measure,
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct DetailColumn;
+    impl TableColumn<Person> for DetailColumn {
+        fn column_name(&self) -> String {
+            "Detail".to_string()
+        }
+        fn render_header(&self, _context: ColumnContext, _attributes: Vec<Attribute>) -> Element {
+            rsx! {
+                th {}
+            }
+        }
+        fn render_cell(
+            &self,
+            _context: ColumnContext,
+            _row: &Person,
+            _attributes: Vec<Attribute>,
+        ) -> Element {
+            rsx! {
+                td {}
+            }
+        }
+    }
+    impl SerializableColumn<Person> for DetailColumn {
+        fn serialize_cell(&self, row: &Person) -> impl Serialize + '_ {
+            Detail {
+                zeta: i128::MAX - row.age as i128,
+                alpha: Measure::Ratio(1.5),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dyn_columns_export_like_tuples() {
+        test_hook_simple(
+            || {
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                let mut tuple = MockExporter::new();
+                TableContext::use_table_context((DetailColumn,))
+                    .serialize(rows.into(), &mut tuple)
+                    .unwrap();
+                let columns: Vec<Box<dyn DynSerializableColumn<Person>>> =
+                    vec![Box::new(DetailColumn)];
+                let mut boxed = MockExporter::new();
+                TableContext::use_table_context(columns)
+                    .serialize(rows.into(), &mut boxed)
+                    .unwrap();
+                (tuple.cells, boxed.cells)
+            },
+            |(tuple, boxed)| {
+                // Wide integers, field order and newtype variants are passed through as is
+                let expected = format!(r#"{{"zeta":{},"alpha":{{"Ratio":1.5}}}}"#, i128::MAX - 30);
+                assert_eq!(tuple, [(0, 0, expected)]);
+                assert_eq!(boxed, tuple);
+            },
+        );
+    }
+
     #[test]
     fn test_export_with_hidden_columns() {
         test_hook(
@@ -634,6 +1204,126 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_export_to_csv() {
+        test_hook_simple(
+            || {
+                let context =
+                    TableContext::use_table_context((NameColumn, AgeColumn, PriorityColumn));
+                let rows = Signal::new(vec![
+                    Person {
+                        name: "Smith, Alice".to_string(),
+                        age: 30,
+                    },
+                    Person {
+                        name: "Bob".to_string(),
+                        age: 25,
+                    },
+                ]);
+                context.data.request_sort(
+                    1,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+
+                let mut exporter = CsvExporter::new(Vec::new());
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                String::from_utf8(exporter.into_inner()).unwrap()
+            },
+            |csv| {
+                assert_eq!(
+                    csv,
+                    "Name,Age,Custom Priority Header\r\nBob,25,High\r\n\"Smith, Alice\",30,High\r\n"
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_export_to_json() {
+        test_hook_simple(
+            || {
+                let context =
+                    TableContext::use_table_context((NameColumn, AgeColumn, PriorityColumn));
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                context.data.hide_column(0);
+
+                let mut exporter = JsonExporter::new(Vec::new());
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                String::from_utf8(exporter.into_inner()).unwrap()
+            },
+            |json| {
+                assert_eq!(json, r#"[{"Age":30,"Custom Priority Header":"High"}]"#);
+            },
+        );
+    }
+
+    #[cfg(feature = "xlsx")]
+    #[test]
+    fn test_export_to_xlsx() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+
+                let mut exporter = XlsxExporter::new(Vec::new());
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                exporter.into_inner()
+            },
+            |workbook| {
+                let (_, sheet) = zip::read_zip(&workbook)
+                    .into_iter()
+                    .find(|(name, _)| name == "xl/worksheets/sheet1.xml")
+                    .unwrap();
+                let sheet = String::from_utf8(sheet).unwrap();
+                assert!(sheet.contains(r#"<c r="A2" t="inlineStr"><is><t>Alice</t></is></c>"#));
+                assert!(sheet.contains(r#"<c r="B2"><v>30</v></c>"#));
+            },
+        );
+    }
+
+    #[test]
+    fn test_export_to_markdown() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(vec![
+                    Person {
+                        name: "Alice".to_string(),
+                        age: 30,
+                    },
+                    Person {
+                        name: "Bob".to_string(),
+                        age: 5,
+                    },
+                ]);
+                context.data.swap_columns(0, 1);
+
+                let mut exporter = MarkdownExporter::new();
+                context.serialize(rows.into(), &mut exporter).unwrap();
+                exporter.into_string()
+            },
+            |markdown| {
+                assert_eq!(
+                    markdown,
+                    concat!(
+                        "| Age | Name  |\n",
+                        "| --: | ----- |\n",
+                        "|  30 | Alice |\n",
+                        "|   5 | Bob   |\n",
+                    )
+                );
+            },
+        );
+    }
+
     #[derive(Clone, PartialEq)]
     struct ExcludedColumn;
     impl TableColumn<Person> for ExcludedColumn {
@@ -841,4 +1531,385 @@ mod tests {
             |_| {},
         );
     }
+
+    #[test]
+    fn test_export_options_columns() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((
+                    NameColumn,
+                    AgeColumn,
+                    PriorityColumn,
+                    ExcludedColumn,
+                ));
+                let rows = Signal::new(vec![Person {
+                    name: "Alice".to_string(),
+                    age: 30,
+                }]);
+                context.data.swap_columns(0, 1);
+                context.data.hide_column(2);
+
+                let export = |options: ExportOptions| {
+                    let mut exporter = MockExporter::new();
+                    context
+                        .serialize_with_options(rows.into(), &options, &mut exporter)
+                        .unwrap();
+                    let headers: Vec<String> =
+                        exporter.headers.into_iter().map(|(_, h)| h).collect();
+                    headers.join(",")
+                };
+                [
+                    export(ExportOptions::default()),
+                    export(ExportOptions {
+                        all_columns: true,
+                        ..Default::default()
+                    }),
+                    export(ExportOptions {
+                        definition_order: true,
+                        ..Default::default()
+                    }),
+                    export(ExportOptions::everything()),
+                ]
+            },
+            |headers| {
+                assert_eq!(
+                    headers,
+                    [
+                        "Age,Name",
+                        "Age,Name,Custom Priority Header",
+                        "Name,Age",
+                        "Name,Age,Custom Priority Header",
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_export_options_rows() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(vec![
+                    Person {
+                        name: "Alice".to_string(),
+                        age: 30,
+                    },
+                    Person {
+                        name: "Bob".to_string(),
+                        age: 25,
+                    },
+                    Person {
+                        name: "Carol".to_string(),
+                        age: 40,
+                    },
+                ]);
+                context.data.request_sort(
+                    1,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Descending,
+                    }),
+                );
+                context.data.set_search_query("o".to_string());
+
+                let export = |options: ExportOptions| {
+                    let mut exporter = MockExporter::new();
+                    context
+                        .serialize_with_options(rows.into(), &options, &mut exporter)
+                        .unwrap();
+                    let rows: Vec<String> = exporter
+                        .events
+                        .into_iter()
+                        .filter_map(|event| event.strip_prefix("begin_row ").map(String::from))
+                        .collect();
+                    rows.join(",")
+                };
+                [
+                    export(ExportOptions::default()),
+                    export(ExportOptions {
+                        original_order: true,
+                        ..Default::default()
+                    }),
+                    export(ExportOptions {
+                        all_rows: true,
+                        ..Default::default()
+                    }),
+                    export(ExportOptions::everything()),
+                    export(ExportOptions::default().with_row_keys(["Alice_30", "Bob_25"])),
+                    export(ExportOptions::everything().with_row_keys(["Carol_40", "Alice_30"])),
+                ]
+            },
+            |rows| {
+                assert_eq!(
+                    rows,
+                    [
+                        "0 Carol_40,1 Bob_25",
+                        "0 Bob_25,1 Carol_40",
+                        "0 Carol_40,1 Alice_30,2 Bob_25",
+                        "0 Alice_30,1 Bob_25,2 Carol_40",
+                        "0 Bob_25",
+                        "0 Alice_30,1 Carol_40",
+                    ]
+                );
+            },
+        );
+    }
+
+    fn people(count: u32) -> Vec<Person> {
+        (0..count)
+            .map(|i| Person {
+                name: format!("P{i}"),
+                age: 20 + i,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_serialize_chunked() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(people(5));
+                context.data.request_sort(
+                    1,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Descending,
+                    }),
+                );
+
+                let progress = Rc::new(RefCell::new(Vec::new()));
+                let progress_clone = progress.clone();
+                let chunked = ChunkedExport::new()
+                    .with_chunk_size(2)
+                    .with_progress(move |p| progress_clone.borrow_mut().push((p.done, p.total)));
+                let mut exporter = CsvExporter::new(Vec::new());
+                let status = futures::executor::block_on(context.serialize_chunked(
+                    rows.into(),
+                    &ExportOptions::default(),
+                    &mut exporter,
+                    chunked,
+                ))
+                .unwrap();
+                let csv = String::from_utf8(exporter.into_inner()).unwrap();
+                (status, csv, progress.take())
+            },
+            |(status, csv, progress)| {
+                assert_eq!(status, ExportStatus::Completed);
+                assert_eq!(
+                    csv,
+                    "Name,Age\r\nP4,24\r\nP3,23\r\nP2,22\r\nP1,21\r\nP0,20\r\n"
+                );
+                assert_eq!(progress, [(0, 5), (2, 5), (4, 5), (5, 5)]);
+            },
+        );
+    }
+
+    #[test]
+    fn test_serialize_chunked_cancel() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                let rows = Signal::new(people(5));
+
+                let chunked = ChunkedExport::new().with_chunk_size(2);
+                let cancel = chunked.cancel_token();
+                let chunked = chunked.with_progress(move |p| {
+                    if p.done >= 2 {
+                        cancel.cancel();
+                    }
+                });
+                let mut exporter = MockExporter::new();
+                let status = futures::executor::block_on(context.serialize_chunked(
+                    rows.into(),
+                    &ExportOptions::default(),
+                    &mut exporter,
+                    chunked,
+                ))
+                .unwrap();
+                (status, exporter.events)
+            },
+            |(status, events)| {
+                assert_eq!(status, ExportStatus::Cancelled);
+                assert!(events.contains(&"end_row 1".to_string()));
+                assert!(!events.contains(&"begin_row 2 P2_22".to_string()));
+                assert!(!events.contains(&"finish".to_string()));
+            },
+        );
+    }
+
+    #[test]
+    fn test_serialize_chunked_skips_removed_rows() {
+        test_hook(
+            || {
+                let context = TableContext::use_table_context((NameColumn,));
+                let rows = Signal::new(people(5));
+                (context, rows)
+            },
+            |(context, mut rows), proxy| match proxy.generation {
+                0 => {
+                    // Remove rows once the first chunk is done
+                    let chunked = ChunkedExport::new()
+                        .with_chunk_size(2)
+                        .with_progress(move |p| {
+                            if p.done == 2 {
+                                rows.write().truncate(3);
+                            }
+                        });
+                    let mut exporter =
+                        CsvExporter::new(Vec::new()).with_line_ending(LineEnding::Lf);
+                    futures::executor::block_on(context.serialize_chunked(
+                        rows.into(),
+                        &ExportOptions::default(),
+                        &mut exporter,
+                        chunked,
+                    ))
+                    .unwrap();
+                    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+                    assert_eq!(csv, "Name\nP0\nP1\nP2\n");
+                }
+                1 => {
+                    // Rerender after signal changes - no action needed
+                }
+                _ => panic!("Unexpected generation: {}", proxy.generation),
+            },
+            |proxy| assert_eq!(proxy.generation, 1),
+        );
+    }
+
+    #[test]
+    fn test_serialize_chunked_follows_moved_rows() {
+        test_hook(
+            || {
+                let context = TableContext::use_table_context((NameColumn, AgeColumn));
+                context.data.request_sort(
+                    1,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Descending,
+                    }),
+                );
+                let rows = Signal::new(people(6));
+                (context, rows)
+            },
+            |(context, mut rows), proxy| match proxy.generation {
+                0 => {
+                    // Once the first chunk is done, remove a row from the middle and insert one
+                    // at the front, shifting the indices of all other rows
+                    let chunked = ChunkedExport::new()
+                        .with_chunk_size(2)
+                        .with_progress(move |p| {
+                            if p.done == 2 {
+                                let mut rows = rows.write();
+                                rows.remove(2);
+                                rows.insert(
+                                    0,
+                                    Person {
+                                        name: "New".to_string(),
+                                        age: 99,
+                                    },
+                                );
+                            }
+                        });
+                    let mut exporter =
+                        CsvExporter::new(Vec::new()).with_line_ending(LineEnding::Lf);
+                    futures::executor::block_on(context.serialize_chunked(
+                        rows.into(),
+                        &ExportOptions::default(),
+                        &mut exporter,
+                        chunked,
+                    ))
+                    .unwrap();
+                    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+                    assert_eq!(csv, "Name,Age\nP5,25\nP4,24\nP3,23\nP1,21\nP0,20\n");
+                }
+                1 => {
+                    // Rerender after signal changes - no action needed
+                }
+                _ => panic!("Unexpected generation: {}", proxy.generation),
+            },
+            |proxy| assert_eq!(proxy.generation, 1),
+        );
+    }
+
+    /// Fails on the cell at the given position, or on `finish` if `None`.
+    struct FailingExporter(Option<(usize, usize)>);
+
+    impl Exporter for FailingExporter {
+        type Error = String;
+
+        fn serialize_header(&mut self, _col: usize, _header: &str) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_cell<'a>(
+            &mut self,
+            row: usize,
+            col: usize,
+            _cell: impl Serialize + 'a,
+        ) -> Result<(), Self::Error> {
+            if self.0 == Some((row, col)) {
+                return Err("boom".to_string());
+            }
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<(), Self::Error> {
+            match self.0 {
+                Some(_) => Ok(()),
+                None => Err("disk full".to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_export_error_context() {
+        test_hook_simple(
+            || {
+                let context = TableContext::use_table_context((NameColumn, PriorityColumn));
+                let rows = Signal::new(vec![
+                    Person {
+                        name: "Bob".to_string(),
+                        age: 25,
+                    },
+                    Person {
+                        name: "Alice".to_string(),
+                        age: 30,
+                    },
+                ]);
+                context.data.request_sort(
+                    0,
+                    SortGesture::AddFirst(Sort {
+                        direction: SortDirection::Ascending,
+                    }),
+                );
+
+                let cell_error = context
+                    .serialize(rows.into(), &mut FailingExporter(Some((1, 1))))
+                    .unwrap_err();
+                let finish_error = context
+                    .serialize(rows.into(), &mut FailingExporter(None))
+                    .unwrap_err();
+                (cell_error, finish_error)
+            },
+            |(cell_error, finish_error)| {
+                assert_eq!(
+                    cell_error,
+                    ExportError {
+                        row_key: Some("Bob_25".to_string()),
+                        row: Some(1),
+                        column_name: Some("Priority".to_string()),
+                        header: Some("Custom Priority Header".to_string()),
+                        source: "boom".to_string(),
+                    }
+                );
+                assert_eq!(
+                    cell_error.to_string(),
+                    "row 'Bob_25', column 'Custom Priority Header' failed: boom"
+                );
+
+                assert_eq!(finish_error.row_key, None);
+                assert_eq!(finish_error.column_name, None);
+                assert_eq!(finish_error.to_string(), "export failed: disk full");
+            },
+        );
+    }
 }
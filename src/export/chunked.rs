@@ -0,0 +1,187 @@
+use std::cell::Cell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// The progress of a chunked export, reported after each chunk.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ExportProgress {
+    /// The number of rows processed so far.
+    pub done: usize,
+    /// The number of rows to export.
+    pub total: usize,
+}
+
+impl ExportProgress {
+    /// Returns the completed fraction between 0.0 and 1.0. An empty export is complete.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
+
+/// How a chunked export ended.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExportStatus {
+    /// All rows were exported and [`Exporter::finish`](crate::Exporter::finish) was called.
+    Completed,
+    /// The export was cancelled between two chunks. The exporter was not finished, so its
+    /// output is incomplete.
+    Cancelled,
+}
+
+/// Cancels a chunked export from elsewhere, e.g. a "Cancel" button.
+///
+/// Clones share the same state.
+#[derive(Clone, Default)]
+pub struct CancelToken(Rc<Cell<bool>>);
+
+impl CancelToken {
+    /// Creates a new CancelToken
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the export to stop before its next chunk.
+    pub fn cancel(&self) {
+        self.0.set(true);
+    }
+
+    /// Returns whether [`cancel`](Self::cancel) was called.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.get()
+    }
+}
+
+impl fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CancelToken")
+            .field(&self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Settings of a chunked export started with
+/// [`TableData::serialize_chunked`](crate::TableData::serialize_chunked).
+///
+/// The row order is computed up front, then rows are serialized `chunk_size` at a time,
+/// yielding to the async runtime before each chunk so the UI stays responsive.
+pub struct ChunkedExport {
+    pub(crate) chunk_size: usize,
+    pub(crate) cancel: CancelToken,
+    pub(crate) on_progress: Option<Box<dyn FnMut(ExportProgress)>>,
+}
+
+impl ChunkedExport {
+    /// The number of rows serialized between two yields by default.
+    pub const DEFAULT_CHUNK_SIZE: usize = 1000;
+
+    /// Creates a new ChunkedExport with chunks of [`DEFAULT_CHUNK_SIZE`](Self::DEFAULT_CHUNK_SIZE)
+    /// rows, no progress callback and a fresh [`CancelToken`].
+    pub fn new() -> Self {
+        Self {
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            cancel: CancelToken::new(),
+            on_progress: None,
+        }
+    }
+
+    /// Sets the number of rows serialized between two yields. Zero is treated as one.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            ..self
+        }
+    }
+
+    /// Sets the token cancelling the export.
+    pub fn with_cancel_token(self, cancel: CancelToken) -> Self {
+        Self { cancel, ..self }
+    }
+
+    /// Sets a callback receiving the progress once before the first chunk and after each chunk.
+    pub fn with_progress(self, on_progress: impl FnMut(ExportProgress) + 'static) -> Self {
+        Self {
+            on_progress: Some(Box::new(on_progress)),
+            ..self
+        }
+    }
+
+    /// Returns a token cancelling this export.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
+    pub(crate) fn report(&mut self, progress: ExportProgress) {
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(progress);
+        }
+    }
+}
+
+impl Default for ChunkedExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ChunkedExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedExport")
+            .field("chunk_size", &self.chunk_size)
+            .field("cancel", &self.cancel)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns a future that is pending once, letting the runtime run other tasks and render.
+pub(crate) fn yield_now() -> impl Future<Output = ()> {
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    YieldNow(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(ExportProgress { done: 0, total: 0 }.fraction(), 1.0);
+        assert_eq!(ExportProgress { done: 1, total: 4 }.fraction(), 0.25);
+    }
+
+    #[test]
+    fn test_cancel_token_is_shared() {
+        let export = ChunkedExport::new().with_chunk_size(0);
+        assert_eq!(export.chunk_size, 1);
+        let token = export.cancel_token();
+        assert!(!export.cancel.is_cancelled());
+        token.cancel();
+        assert!(export.cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_yield_now() {
+        futures::executor::block_on(yield_now());
+    }
+}
@@ -0,0 +1,423 @@
+use super::value::CellValue;
+use crate::Exporter;
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::io::Write;
+
+/// When [`CsvExporter`] encloses fields in double quotes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum QuoteStyle {
+    /// Quotes fields containing the delimiter, a double quote or a line break.
+    #[default]
+    Necessary,
+    /// Quotes every field.
+    Always,
+    /// Quotes every field that is not a number, and fields that need quoting.
+    NonNumeric,
+    /// Never quotes fields. The output is not valid CSV if a field contains the delimiter,
+    /// a double quote or a line break.
+    Never,
+}
+
+/// The line terminator written after each record by [`CsvExporter`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LineEnding {
+    /// `\r\n`, as specified by RFC 4180.
+    #[default]
+    CrLf,
+    /// `\n`.
+    Lf,
+}
+
+impl LineEnding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CrLf => "\r\n",
+            Self::Lf => "\n",
+        }
+    }
+}
+
+/// The error returned by [`CsvExporter`].
+#[derive(Debug)]
+pub enum CsvError {
+    /// Writing to the output failed.
+    Io(std::io::Error),
+    /// The delimiter set with [`with_delimiter`](CsvExporter::with_delimiter) is a double quote
+    /// or a line break, which would make the output impossible to parse.
+    Delimiter(char),
+    /// A cell could not be represented as a single CSV field, e.g. a sequence or a struct.
+    Cell {
+        /// The exported row index.
+        row: usize,
+        /// The exported column index.
+        col: usize,
+        /// Why the cell could not be written.
+        message: String,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to write CSV: {error}"),
+            Self::Delimiter(delimiter) => write!(f, "invalid CSV delimiter {delimiter:?}"),
+            Self::Cell { row, col, message } => {
+                write!(f, "invalid cell at row {row}, column {col}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Delimiter(_) | Self::Cell { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// An [`Exporter`] writing RFC 4180 CSV to any [`Write`].
+///
+/// The headers form the first record, followed by one record per row. Cells are flattened to
+/// plain text: strings are written without JSON quoting, numbers and booleans as is, `None`
+/// and `()` as empty fields, unit enum variants as their name, and `Some` and newtype structs
+/// as their inner value. Other values, like sequences or structs, fail with [`CsvError::Cell`].
+///
+/// Records are terminated by [`end_headers`](Exporter::end_headers) and
+/// [`end_row`](Exporter::end_row), and [`finish`](Exporter::finish) flushes the writer, so
+/// export with [`TableContext::serialize`](crate::TableContext::serialize) and take the writer
+/// back with [`into_inner`](Self::into_inner). Wrap files in a
+/// [`BufWriter`](std::io::BufWriter), as fields are written one by one.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # use serde::Serialize;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # impl SerializableColumn<User> for Col {
+/// #     fn serialize_cell(&self, row: &User) -> impl Serialize + '_ { row.id }
+/// # }
+/// # fn export(data: TableData<(Col,), User>) -> Result<String, ExportError<CsvError>> {
+/// let mut exporter = CsvExporter::new(Vec::new())
+///     .with_delimiter(';')
+///     .with_line_ending(LineEnding::Lf)
+///     .with_bom(true);
+/// data.serialize(&mut exporter)?;
+/// let bytes = exporter.into_inner();
+/// # Ok(String::from_utf8(bytes).unwrap())
+/// # }
+/// ```
+pub struct CsvExporter<W: Write> {
+    writer: W,
+    delimiter: char,
+    quote_style: QuoteStyle,
+    line_ending: LineEnding,
+    bom: bool,
+    /// Whether the BOM, if any, was written
+    started: bool,
+    /// The number of fields written to the current record
+    fields: usize,
+}
+
+impl<W: Write> CsvExporter<W> {
+    /// Creates a new CsvExporter with a comma delimiter, [`QuoteStyle::Necessary`],
+    /// [`LineEnding::CrLf`] and no byte order mark.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            delimiter: ',',
+            quote_style: QuoteStyle::default(),
+            line_ending: LineEnding::default(),
+            bom: false,
+            started: false,
+            fields: 0,
+        }
+    }
+
+    /// Sets the field delimiter, e.g. `';'` or `'\t'`.
+    ///
+    /// A double quote or a line break fails the export with [`CsvError::Delimiter`] before
+    /// anything is written.
+    pub fn with_delimiter(self, delimiter: char) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Sets when fields are enclosed in double quotes.
+    pub fn with_quote_style(self, quote_style: QuoteStyle) -> Self {
+        Self {
+            quote_style,
+            ..self
+        }
+    }
+
+    /// Sets the line terminator written after each record.
+    pub fn with_line_ending(self, line_ending: LineEnding) -> Self {
+        Self {
+            line_ending,
+            ..self
+        }
+    }
+
+    /// Sets whether to start the output with a UTF-8 byte order mark, which helps spreadsheet
+    /// applications like Excel detect the encoding.
+    pub fn with_bom(self, bom: bool) -> Self {
+        Self { bom, ..self }
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn start(&mut self) -> Result<(), CsvError> {
+        if !self.started {
+            if matches!(self.delimiter, '"' | '\r' | '\n') {
+                return Err(CsvError::Delimiter(self.delimiter));
+            }
+            self.started = true;
+            if self.bom {
+                self.writer.write_all("\u{feff}".as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Terminates the current record, unless it has no fields.
+    fn end_record(&mut self) -> Result<(), CsvError> {
+        if self.fields > 0 {
+            self.fields = 0;
+            self.writer
+                .write_all(self.line_ending.as_str().as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Writes a field of the current record.
+    fn write_field(&mut self, text: &str, is_number: bool) -> Result<(), CsvError> {
+        self.start()?;
+        if self.fields > 0 {
+            write!(self.writer, "{}", self.delimiter)?;
+        }
+        self.fields += 1;
+
+        let needs_quotes = text.contains([self.delimiter, '"', '\r', '\n']);
+        let quote = match self.quote_style {
+            QuoteStyle::Necessary => needs_quotes,
+            QuoteStyle::Always => true,
+            QuoteStyle::NonNumeric => needs_quotes || !is_number,
+            QuoteStyle::Never => false,
+        };
+        if quote {
+            write!(self.writer, "\"{}\"", text.replace('"', "\"\""))?;
+        } else {
+            self.writer.write_all(text.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Exporter for CsvExporter<W> {
+    type Error = CsvError;
+
+    fn begin_table(&mut self, _columns: usize) -> Result<(), Self::Error> {
+        self.start()
+    }
+
+    fn serialize_header(&mut self, _col: usize, header: &str) -> Result<(), Self::Error> {
+        self.write_field(header, false)
+    }
+
+    fn end_headers(&mut self) -> Result<(), Self::Error> {
+        self.end_record()
+    }
+
+    fn serialize_cell<'a>(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: impl Serialize + 'a,
+    ) -> Result<(), Self::Error> {
+        let value = CellValue::from_serialize(cell).map_err(|error| CsvError::Cell {
+            row,
+            col,
+            message: error.to_string(),
+        })?;
+        self.write_field(&value.to_string(), value.is_number())
+    }
+
+    fn end_row(&mut self, _row: usize) -> Result<(), Self::Error> {
+        self.end_record()
+    }
+
+    fn finish(&mut self) -> Result<(), Self::Error> {
+        self.start()?;
+        self.end_record()?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export(exporter: CsvExporter<Vec<u8>>) -> String {
+        let mut exporter = exporter;
+        exporter.begin_table(2).unwrap();
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.end_headers().unwrap();
+        exporter.begin_row(0, "1").unwrap();
+        exporter.serialize_cell(0, 0, "Alice").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.end_row(0).unwrap();
+        exporter.begin_row(1, "2").unwrap();
+        exporter.serialize_cell(1, 0, "Smith, \"Bob\"").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        exporter.end_row(1).unwrap();
+        exporter.finish().unwrap();
+        String::from_utf8(exporter.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            export(CsvExporter::new(Vec::new())),
+            "Name,Age\r\nAlice,30\r\n\"Smith, \"\"Bob\"\"\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_quote_styles() {
+        let exporter = CsvExporter::new(Vec::new()).with_line_ending(LineEnding::Lf);
+        assert_eq!(
+            export(exporter.with_quote_style(QuoteStyle::Always)),
+            "\"Name\",\"Age\"\n\"Alice\",\"30\"\n\"Smith, \"\"Bob\"\"\",\"\"\n"
+        );
+
+        let exporter = CsvExporter::new(Vec::new()).with_line_ending(LineEnding::Lf);
+        assert_eq!(
+            export(exporter.with_quote_style(QuoteStyle::NonNumeric)),
+            "\"Name\",\"Age\"\n\"Alice\",30\n\"Smith, \"\"Bob\"\"\",\"\"\n"
+        );
+
+        let exporter = CsvExporter::new(Vec::new()).with_line_ending(LineEnding::Lf);
+        assert_eq!(
+            export(exporter.with_quote_style(QuoteStyle::Never)),
+            "Name,Age\nAlice,30\nSmith, \"Bob\",\n"
+        );
+    }
+
+    #[test]
+    fn test_delimiter_and_bom() {
+        let exporter = CsvExporter::new(Vec::new())
+            .with_delimiter(';')
+            .with_bom(true);
+        assert_eq!(
+            export(exporter),
+            "\u{feff}Name;Age\r\nAlice;30\r\n\"Smith, \"\"Bob\"\"\";\r\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_delimiters() {
+        for delimiter in ['"', '\r', '\n'] {
+            let mut exporter = CsvExporter::new(Vec::new())
+                .with_delimiter(delimiter)
+                .with_bom(true);
+            let error = exporter.begin_table(2).unwrap_err();
+            assert!(matches!(error, CsvError::Delimiter(d) if d == delimiter));
+            // Later calls keep failing, and nothing gets written
+            let error = exporter.serialize_header(0, "Name").unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("invalid CSV delimiter {delimiter:?}")
+            );
+            assert!(exporter.finish().is_err());
+            assert!(exporter.into_inner().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_line_breaks_are_quoted() {
+        let mut exporter = CsvExporter::new(Vec::new());
+        exporter.serialize_cell(0, 0, "line 1\nline 2").unwrap();
+        exporter.serialize_cell(0, 1, "a\rb").unwrap();
+        exporter.finish().unwrap();
+        let output = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(output, "\"line 1\nline 2\",\"a\rb\"\r\n");
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut exporter = CsvExporter::new(Vec::new());
+        exporter.finish().unwrap();
+        assert_eq!(exporter.into_inner(), b"");
+
+        let mut exporter = CsvExporter::new(Vec::new()).with_bom(true);
+        exporter.finish().unwrap();
+        assert_eq!(exporter.into_inner(), "\u{feff}".as_bytes());
+    }
+
+    #[test]
+    fn test_records_without_fields() {
+        let mut exporter = CsvExporter::new(Vec::new());
+        exporter.begin_table(0).unwrap();
+        exporter.end_headers().unwrap();
+        exporter.begin_row(0, "1").unwrap();
+        exporter.end_row(0).unwrap();
+        exporter.finish().unwrap();
+        assert_eq!(exporter.into_inner(), b"");
+    }
+
+    #[test]
+    fn test_nested_value_error() {
+        let mut exporter = CsvExporter::new(Vec::new());
+        exporter.serialize_cell(0, 0, "ok").unwrap();
+        let error = exporter.serialize_cell(0, 1, vec![1, 2]).unwrap_err();
+        assert!(matches!(error, CsvError::Cell { row: 0, col: 1, .. }));
+        assert_eq!(
+            error.to_string(),
+            "invalid cell at row 0, column 1: a sequence cannot be represented as a single value"
+        );
+    }
+
+    #[test]
+    fn test_io_error() {
+        struct FailingWriter;
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk full"))
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut exporter = CsvExporter::new(FailingWriter);
+        let error = exporter.serialize_header(0, "Name").unwrap_err();
+        assert!(matches!(error, CsvError::Io(_)));
+        assert_eq!(error.to_string(), "failed to write CSV: disk full");
+    }
+}
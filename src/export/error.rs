@@ -0,0 +1,109 @@
+use std::fmt::{self, Display};
+
+/// An error of an [`Exporter`](crate::Exporter), with the row and column being exported
+/// when it occurred.
+///
+/// Returned by [`TableContext::serialize`](crate::TableContext::serialize) and its variants.
+/// The row and column are `None` for errors outside of them, e.g. in
+/// [`Exporter::finish`](crate::Exporter::finish).
+///
+/// Displays as `row 'task-42', column 'Due' failed: <source>`. Since the message already
+/// includes the exporter's error, [`Error::source`](std::error::Error::source) returns `None`
+/// so that error reporters don't print it twice; it is available as the `source` field.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExportError<E> {
+    /// The key of the row being exported.
+    pub row_key: Option<String>,
+    /// The exported row index.
+    pub row: Option<usize>,
+    /// The [`column_name`](crate::TableColumn::column_name) of the column being exported.
+    pub column_name: Option<String>,
+    /// The [`header`](crate::SerializableColumn::header) text of the column being exported.
+    pub header: Option<String>,
+    /// The error returned by the exporter.
+    pub source: E,
+}
+
+impl<E> ExportError<E> {
+    pub(crate) fn new(source: E) -> Self {
+        Self {
+            row_key: None,
+            row: None,
+            column_name: None,
+            header: None,
+            source,
+        }
+    }
+
+    pub(crate) fn with_row(self, row: usize, key: impl Into<String>) -> Self {
+        Self {
+            row: Some(row),
+            row_key: Some(key.into()),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_column(self, column_name: String, header: String) -> Self {
+        Self {
+            column_name: Some(column_name),
+            header: Some(header),
+            ..self
+        }
+    }
+
+    /// Returns the error returned by the exporter.
+    pub fn into_source(self) -> E {
+        self.source
+    }
+}
+
+impl<E: Display> Display for ExportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Name columns by the header users see, falling back to the column name
+        let column = self.header.as_ref().filter(|h| !h.is_empty());
+        let column = column.or(self.column_name.as_ref());
+        match (&self.row_key, column) {
+            (Some(key), Some(column)) => write!(f, "row '{key}', column '{column}' failed: ")?,
+            (Some(key), None) => write!(f, "row '{key}' failed: ")?,
+            (None, Some(column)) => write!(f, "column '{column}' failed: ")?,
+            (None, None) => write!(f, "export failed: ")?,
+        }
+        write!(f, "{}", self.source)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ExportError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ExportError::new("disk full");
+        assert_eq!(error.to_string(), "export failed: disk full");
+
+        let error = error.with_row(3, "task-42");
+        assert_eq!(error.to_string(), "row 'task-42' failed: disk full");
+
+        let error = error.with_column("due".to_string(), "Due".to_string());
+        assert_eq!(
+            error.to_string(),
+            "row 'task-42', column 'Due' failed: disk full"
+        );
+        assert_eq!(error.row, Some(3));
+
+        let error = ExportError::new("bad").with_column("due".to_string(), String::new());
+        assert_eq!(error.to_string(), "column 'due' failed: bad");
+    }
+
+    #[test]
+    fn test_source_is_not_repeated() {
+        use std::error::Error;
+
+        let source = std::io::Error::other("disk full");
+        let error = ExportError::new(source).with_row(3, "task-42");
+        assert_eq!(error.to_string(), "row 'task-42' failed: disk full");
+        assert!(error.source().is_none());
+    }
+}
@@ -0,0 +1,67 @@
+use super::value::CellValue;
+
+/// Headers and cells buffered by exporters that need the whole table before writing it.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct Grid {
+    pub(crate) headers: Vec<String>,
+    pub(crate) rows: Vec<Vec<CellValue>>,
+}
+
+impl Grid {
+    pub(crate) fn set_header(&mut self, col: usize, header: &str) {
+        if self.headers.len() <= col {
+            self.headers.resize(col + 1, String::new());
+        }
+        self.headers[col] = header.to_string();
+    }
+
+    /// Sets a cell, filling the rows and cells before it with empty values
+    pub(crate) fn set_cell(&mut self, row: usize, col: usize, value: CellValue) {
+        if self.rows.len() <= row {
+            self.rows.resize(row + 1, Vec::new());
+        }
+        let cells = &mut self.rows[row];
+        if cells.len() <= col {
+            cells.resize(col + 1, CellValue::Empty);
+        }
+        cells[col] = value;
+    }
+
+    /// Returns the number of columns of the widest row, headers included
+    pub(crate) fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the values of a column, skipping rows that are too short to have it
+    pub(crate) fn column(&self, col: usize) -> impl Iterator<Item = &CellValue> {
+        self.rows.iter().filter_map(move |cells| cells.get(col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_cell_fills_gaps() {
+        let mut grid = Grid::default();
+        grid.set_cell(1, 2, CellValue::Int(1));
+        assert_eq!(
+            grid.rows,
+            [
+                vec![],
+                vec![CellValue::Empty, CellValue::Empty, CellValue::Int(1)]
+            ]
+        );
+        assert_eq!(grid.column_count(), 3);
+
+        grid.set_header(3, "D");
+        assert_eq!(grid.headers, ["", "", "", "D"]);
+        assert_eq!(grid.column_count(), 4);
+    }
+}
@@ -0,0 +1,160 @@
+use super::grid::Grid;
+use super::value::{CellError, CellValue};
+use crate::Exporter;
+use serde::Serialize;
+
+/// An [`Exporter`] rendering a standalone HTML `<table>`, e.g. for emails.
+///
+/// The headers form a `<thead>` row of `<th>` elements, omitted if no column has a header,
+/// and each row a `<tbody>` row of `<td>` elements. Cells are flattened to plain text like in
+/// [`CsvExporter`](crate::CsvExporter), and all text is escaped.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::{Exporter, HtmlExporter};
+///
+/// let mut exporter = HtmlExporter::new();
+/// exporter.serialize_header(0, "Name").unwrap();
+/// exporter.serialize_cell(0, 0, "<Alice>").unwrap();
+///
+/// assert_eq!(
+///     exporter.into_string(),
+///     "<table>\n\
+///      <thead>\n<tr><th>Name</th></tr>\n</thead>\n\
+///      <tbody>\n<tr><td>&lt;Alice&gt;</td></tr>\n</tbody>\n\
+///      </table>\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HtmlExporter {
+    grid: Grid,
+}
+
+impl HtmlExporter {
+    /// Creates a new HtmlExporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the table.
+    ///
+    /// Rows with fewer cells than the widest row are padded with empty cells.
+    pub fn into_string(self) -> String {
+        let columns = self.grid.column_count();
+        let mut html = String::from("<table>\n");
+        if self.grid.headers.iter().any(|header| !header.is_empty()) {
+            html.push_str("<thead>\n<tr>");
+            for col in 0..columns {
+                let header = self.grid.headers.get(col).map_or("", String::as_str);
+                html.push_str(&format!("<th>{}</th>", escape(header)));
+            }
+            html.push_str("</tr>\n</thead>\n");
+        }
+        html.push_str("<tbody>\n");
+        for cells in &self.grid.rows {
+            html.push_str("<tr>");
+            for col in 0..columns {
+                let text = cells.get(col).map(ToString::to_string).unwrap_or_default();
+                html.push_str(&format!("<td>{}</td>", escape(&text)));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n");
+        html
+    }
+}
+
+impl Exporter for HtmlExporter {
+    type Error = CellError;
+
+    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
+        self.grid.set_header(col, header);
+        Ok(())
+    }
+
+    fn serialize_cell<'a>(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: impl Serialize + 'a,
+    ) -> Result<(), Self::Error> {
+        let value = CellValue::from_cell(row, col, cell)?;
+        self.grid.set_cell(row, col, value);
+        Ok(())
+    }
+}
+
+/// Escapes the characters with a special meaning in HTML text and attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut exporter = HtmlExporter::new();
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.serialize_cell(0, 0, "Alice").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.serialize_cell(1, 0, "Bob").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            concat!(
+                "<table>\n",
+                "<thead>\n<tr><th>Name</th><th>Age</th></tr>\n</thead>\n",
+                "<tbody>\n",
+                "<tr><td>Alice</td><td>30</td></tr>\n",
+                "<tr><td>Bob</td><td></td></tr>\n",
+                "</tbody>\n",
+                "</table>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        let mut exporter = HtmlExporter::new();
+        exporter.serialize_header(0, "<b>\"Q&A\"</b>").unwrap();
+        exporter.serialize_cell(0, 0, "it's <script>").unwrap();
+        let html = exporter.into_string();
+        assert!(html.contains("<th>&lt;b&gt;&quot;Q&amp;A&quot;&lt;/b&gt;</th>"));
+        assert!(html.contains("<td>it&#39;s &lt;script&gt;</td>"));
+    }
+
+    #[test]
+    fn test_without_headers() {
+        let mut exporter = HtmlExporter::new();
+        exporter.serialize_cell(0, 1, true).unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            "<table>\n<tbody>\n<tr><td></td><td>true</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_value_error() {
+        let mut exporter = HtmlExporter::new();
+        let error = exporter.serialize_cell(0, 0, (1, 2)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid cell at row 0, column 0: a tuple cannot be represented as a single value"
+        );
+    }
+}
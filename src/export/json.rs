@@ -0,0 +1,394 @@
+use crate::Exporter;
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::io::Write;
+
+/// The shape of the JSON written by [`JsonExporter`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum JsonLayout {
+    /// An array with one object per row, keyed by the column headers:
+    /// `[{"Name":"Alice","Age":30}]`
+    #[default]
+    Objects,
+    /// An array of arrays, starting with the headers:
+    /// `[["Name","Age"],["Alice",30]]`
+    Arrays,
+    /// Newline-delimited JSON with one object per row, keyed by the column headers:
+    /// `{"Name":"Alice","Age":30}\n`
+    Lines,
+}
+
+/// The error returned by [`JsonExporter`].
+#[derive(Debug)]
+pub enum JsonError {
+    /// Writing to the output failed.
+    Io(std::io::Error),
+    /// A cell could not be serialized to JSON, e.g. a map with non-string keys.
+    Cell {
+        /// The exported row index.
+        row: usize,
+        /// The exported column index.
+        col: usize,
+        /// The serialization error.
+        source: serde_json::Error,
+    },
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to write JSON: {error}"),
+            Self::Cell { row, col, source } => {
+                write!(f, "invalid cell at row {row}, column {col}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Cell { source, .. } => Some(source),
+        }
+    }
+}
+
+impl From<std::io::Error> for JsonError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// An [`Exporter`] writing JSON or newline-delimited JSON to any [`Write`].
+///
+/// Cells keep their native serde representation: numbers stay numbers, `None` becomes `null`,
+/// and enums and structs serialize as their [`Serialize`] implementation dictates.
+/// See [`JsonLayout`] for the available shapes. Rows are written as soon as they are complete,
+/// so large tables can be streamed.
+///
+/// In the object layouts, each cell is keyed by its column header, so columns should have
+/// distinct [`SerializableColumn::header`](crate::SerializableColumn::header)s.
+///
+/// Records are opened by [`begin_row`](Exporter::begin_row) and closed by
+/// [`end_row`](Exporter::end_row), and [`finish`](Exporter::finish) closes the top-level array,
+/// so export with [`TableContext::serialize`](crate::TableContext::serialize) and take the
+/// writer back with [`into_inner`](Self::into_inner).
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # use serde::Serialize;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # impl SerializableColumn<User> for Col {
+/// #     fn serialize_cell(&self, row: &User) -> impl Serialize + '_ { row.id }
+/// # }
+/// # fn export(data: TableData<(Col,), User>) -> Result<Vec<u8>, ExportError<JsonError>> {
+/// let mut exporter = JsonExporter::new(Vec::new()).with_layout(JsonLayout::Lines);
+/// data.serialize(&mut exporter)?;
+/// let ndjson = exporter.into_inner();
+/// # Ok(ndjson)
+/// # }
+/// ```
+pub struct JsonExporter<W: Write> {
+    writer: W,
+    layout: JsonLayout,
+    /// The headers by column, used as object keys
+    headers: Vec<String>,
+    /// Whether the opening bracket of the top-level array, if any, was written
+    started: bool,
+    /// Whether a record was opened and not closed yet
+    in_record: bool,
+    /// The number of records opened so far
+    records: usize,
+    /// The number of values written in the current record
+    fields: usize,
+}
+
+impl<W: Write> JsonExporter<W> {
+    /// Creates a new JsonExporter with the [`JsonLayout::Objects`] layout.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            layout: JsonLayout::default(),
+            headers: Vec::new(),
+            started: false,
+            in_record: false,
+            records: 0,
+            fields: 0,
+        }
+    }
+
+    /// Sets the shape of the output.
+    pub fn with_layout(self, layout: JsonLayout) -> Self {
+        Self { layout, ..self }
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn start(&mut self) -> Result<(), JsonError> {
+        if !self.started {
+            self.started = true;
+            if self.layout != JsonLayout::Lines {
+                self.writer.write_all(b"[")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn open_record(&mut self) -> Result<(), JsonError> {
+        self.start()?;
+        if self.in_record {
+            self.close_record()?;
+        }
+        if self.records > 0 && self.layout != JsonLayout::Lines {
+            self.writer.write_all(b",")?;
+        }
+        let open: &[u8] = match self.layout {
+            JsonLayout::Arrays => b"[",
+            JsonLayout::Objects | JsonLayout::Lines => b"{",
+        };
+        self.writer.write_all(open)?;
+        self.records += 1;
+        self.fields = 0;
+        self.in_record = true;
+        Ok(())
+    }
+
+    fn close_record(&mut self) -> Result<(), JsonError> {
+        if !self.in_record {
+            return Ok(());
+        }
+        let close: &[u8] = match self.layout {
+            JsonLayout::Arrays => b"]",
+            JsonLayout::Objects => b"}",
+            JsonLayout::Lines => b"}\n",
+        };
+        self.writer.write_all(close)?;
+        self.in_record = false;
+        Ok(())
+    }
+
+    /// Starts the next value of the current record.
+    fn begin_value(&mut self) -> Result<(), JsonError> {
+        if self.fields > 0 {
+            self.writer.write_all(b",")?;
+        }
+        self.fields += 1;
+        Ok(())
+    }
+
+    fn write_json(&mut self, value: &impl Serialize) -> Result<(), JsonError> {
+        serde_json::to_writer(&mut self.writer, value).map_err(std::io::Error::from)?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Exporter for JsonExporter<W> {
+    type Error = JsonError;
+
+    fn begin_table(&mut self, _columns: usize) -> Result<(), Self::Error> {
+        self.start()?;
+        if self.layout == JsonLayout::Arrays {
+            self.open_record()?;
+        }
+        Ok(())
+    }
+
+    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
+        if self.headers.len() <= col {
+            self.headers.resize(col + 1, String::new());
+        }
+        self.headers[col] = header.to_string();
+        if self.layout == JsonLayout::Arrays {
+            self.begin_value()?;
+            self.write_json(&header)?;
+        }
+        Ok(())
+    }
+
+    fn end_headers(&mut self) -> Result<(), Self::Error> {
+        self.close_record()
+    }
+
+    fn begin_row(&mut self, _row: usize, _key: &str) -> Result<(), Self::Error> {
+        self.open_record()
+    }
+
+    fn serialize_cell<'a>(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: impl Serialize + 'a,
+    ) -> Result<(), Self::Error> {
+        // Serialize first so that a failing cell does not leave partial output behind
+        let value =
+            serde_json::to_vec(&cell).map_err(|source| JsonError::Cell { row, col, source })?;
+        self.begin_value()?;
+        if self.layout != JsonLayout::Arrays {
+            let key = self
+                .headers
+                .get(col)
+                .cloned()
+                .unwrap_or_else(|| col.to_string());
+            self.write_json(&key)?;
+            self.writer.write_all(b":")?;
+        }
+        self.writer.write_all(&value)?;
+        Ok(())
+    }
+
+    fn end_row(&mut self, _row: usize) -> Result<(), Self::Error> {
+        self.close_record()
+    }
+
+    fn finish(&mut self) -> Result<(), Self::Error> {
+        self.start()?;
+        self.close_record()?;
+        if self.layout != JsonLayout::Lines {
+            self.writer.write_all(b"]")?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    enum Status {
+        Open,
+        Blocked { reason: String },
+    }
+
+    fn export(layout: JsonLayout) -> String {
+        let mut exporter = JsonExporter::new(Vec::new()).with_layout(layout);
+        exporter.begin_table(3).unwrap();
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.serialize_header(2, "Status").unwrap();
+        exporter.end_headers().unwrap();
+        exporter.begin_row(0, "1").unwrap();
+        exporter.serialize_cell(0, 0, "Alice").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.serialize_cell(0, 2, Status::Open).unwrap();
+        exporter.end_row(0).unwrap();
+        exporter.begin_row(1, "2").unwrap();
+        exporter.serialize_cell(1, 0, "Bob").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        let status = Status::Blocked {
+            reason: "waiting".to_string(),
+        };
+        exporter.serialize_cell(1, 2, status).unwrap();
+        exporter.end_row(1).unwrap();
+        exporter.finish().unwrap();
+        String::from_utf8(exporter.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_objects() {
+        let json = export(JsonLayout::Objects);
+        assert_eq!(
+            json,
+            r#"[{"Name":"Alice","Age":30,"Status":"Open"},{"Name":"Bob","Age":null,"Status":{"Blocked":{"reason":"waiting"}}}]"#
+        );
+        // The output is valid JSON
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["Age"], 30);
+    }
+
+    #[test]
+    fn test_arrays() {
+        assert_eq!(
+            export(JsonLayout::Arrays),
+            r#"[["Name","Age","Status"],["Alice",30,"Open"],["Bob",null,{"Blocked":{"reason":"waiting"}}]]"#
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let ndjson = export(JsonLayout::Lines);
+        assert_eq!(
+            ndjson,
+            concat!(
+                r#"{"Name":"Alice","Age":30,"Status":"Open"}"#,
+                "\n",
+                r#"{"Name":"Bob","Age":null,"Status":{"Blocked":{"reason":"waiting"}}}"#,
+                "\n"
+            )
+        );
+        for line in ndjson.lines() {
+            serde_json::from_str::<serde_json::Value>(line).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_escaped_headers() {
+        let mut exporter = JsonExporter::new(Vec::new());
+        exporter.begin_table(1).unwrap();
+        exporter.serialize_header(0, "say \"hi\"").unwrap();
+        exporter.end_headers().unwrap();
+        exporter.begin_row(0, "1").unwrap();
+        exporter.serialize_cell(0, 0, 1.5).unwrap();
+        exporter.end_row(0).unwrap();
+        exporter.finish().unwrap();
+        let json = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(json, r#"[{"say \"hi\"":1.5}]"#);
+    }
+
+    #[test]
+    fn test_empty() {
+        let finish = |layout| {
+            let mut exporter = JsonExporter::new(Vec::new()).with_layout(layout);
+            exporter.finish().unwrap();
+            String::from_utf8(exporter.into_inner()).unwrap()
+        };
+        assert_eq!(finish(JsonLayout::Objects), "[]");
+        assert_eq!(finish(JsonLayout::Arrays), "[]");
+        assert_eq!(finish(JsonLayout::Lines), "");
+
+        // Headers without rows
+        let mut exporter = JsonExporter::new(Vec::new()).with_layout(JsonLayout::Arrays);
+        exporter.begin_table(1).unwrap();
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.end_headers().unwrap();
+        exporter.finish().unwrap();
+        let json = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(json, r#"[["Name"]]"#);
+    }
+
+    #[test]
+    fn test_cell_error() {
+        let mut exporter = JsonExporter::new(Vec::new());
+        exporter.begin_table(1).unwrap();
+        exporter.serialize_header(0, "Map").unwrap();
+        exporter.end_headers().unwrap();
+        exporter.begin_row(3, "1").unwrap();
+        let map = std::collections::HashMap::from([((1, 2), "x")]);
+        let error = exporter.serialize_cell(3, 0, map).unwrap_err();
+        assert!(matches!(error, JsonError::Cell { row: 3, col: 0, .. }));
+
+        // Nothing was written for the failed cell
+        exporter.finish().unwrap();
+        let json = String::from_utf8(exporter.into_inner()).unwrap();
+        assert_eq!(json, "[{}]");
+    }
+}
@@ -0,0 +1,252 @@
+use super::grid::Grid;
+use super::value::{CellError, CellValue};
+use crate::Exporter;
+use serde::Serialize;
+
+/// The alignment of a column in a [`MarkdownExporter`] table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Alignment {
+    /// `:--`
+    Left,
+    /// `:-:`
+    Center,
+    /// `--:`
+    Right,
+}
+
+/// An [`Exporter`] rendering a GitHub-flavored Markdown table.
+///
+/// Cells are flattened to plain text like in [`CsvExporter`](crate::CsvExporter). Pipes are
+/// escaped and line breaks become `<br>`, so every row stays on one line. Columns are padded to
+/// a common width so the source is readable too.
+///
+/// Columns are aligned as set with [`with_alignment`](Self::with_alignment). Other columns
+/// are right-aligned if all their non-empty cells are numbers, and have no alignment hint
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::{Alignment, Exporter, MarkdownExporter};
+///
+/// let mut exporter = MarkdownExporter::new().with_alignment(0, Alignment::Center);
+/// exporter.serialize_header(0, "Name").unwrap();
+/// exporter.serialize_header(1, "Age").unwrap();
+/// exporter.serialize_cell(0, 0, "Alice | Bob").unwrap();
+/// exporter.serialize_cell(0, 1, 30).unwrap();
+///
+/// assert_eq!(
+///     exporter.into_string(),
+///     "|     Name     | Age |\n\
+///      | :----------: | --: |\n\
+///      | Alice \\| Bob |  30 |\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MarkdownExporter {
+    grid: Grid,
+    alignments: Vec<Option<Alignment>>,
+}
+
+impl MarkdownExporter {
+    /// Creates a new MarkdownExporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the alignment of the exported column at index `col`.
+    pub fn with_alignment(mut self, col: usize, alignment: Alignment) -> Self {
+        if self.alignments.len() <= col {
+            self.alignments.resize(col + 1, None);
+        }
+        self.alignments[col] = Some(alignment);
+        self
+    }
+
+    /// Returns the table, or an empty string if nothing was exported.
+    pub fn into_string(self) -> String {
+        let columns = self.grid.column_count();
+        if columns == 0 {
+            return String::new();
+        }
+        let cell = |cells: &[String], col: usize| cells.get(col).cloned().unwrap_or_default();
+        let headers: Vec<String> = (0..columns)
+            .map(|col| escape(self.grid.headers.get(col).map_or("", String::as_str)))
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .grid
+            .rows
+            .iter()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|value| escape(&value.to_string()))
+                    .collect()
+            })
+            .collect();
+        let alignments: Vec<Option<Alignment>> = (0..columns)
+            .map(|col| {
+                self.alignments.get(col).copied().flatten().or_else(|| {
+                    let mut values = self.grid.column(col).filter(|v| **v != CellValue::Empty);
+                    let mut numeric = values.next().is_some_and(CellValue::is_number);
+                    numeric &= values.all(CellValue::is_number);
+                    numeric.then_some(Alignment::Right)
+                })
+            })
+            .collect();
+        let widths: Vec<usize> = (0..columns)
+            .map(|col| {
+                rows.iter()
+                    .map(|cells| cell(cells, col).chars().count())
+                    .fold(headers[col].chars().count().max(3), usize::max)
+            })
+            .collect();
+
+        let line = |cells: &[String]| {
+            let cells: Vec<String> = (0..columns)
+                .map(|col| {
+                    let text = cell(cells, col);
+                    let width = widths[col];
+                    match alignments[col] {
+                        Some(Alignment::Right) => format!("{text:>width$}"),
+                        Some(Alignment::Center) => format!("{text:^width$}"),
+                        Some(Alignment::Left) | None => format!("{text:<width$}"),
+                    }
+                })
+                .collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+
+        let mut table = line(&headers);
+        let separators: Vec<String> = (0..columns)
+            .map(|col| {
+                let dashes = widths[col];
+                match alignments[col] {
+                    None => "-".repeat(dashes),
+                    Some(Alignment::Left) => format!(":{}", "-".repeat(dashes - 1)),
+                    Some(Alignment::Right) => format!("{}:", "-".repeat(dashes - 1)),
+                    Some(Alignment::Center) => format!(":{}:", "-".repeat(dashes - 2)),
+                }
+            })
+            .collect();
+        table.push_str(&format!("| {} |\n", separators.join(" | ")));
+        for cells in &rows {
+            table.push_str(&line(cells));
+        }
+        table
+    }
+}
+
+impl Exporter for MarkdownExporter {
+    type Error = CellError;
+
+    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
+        self.grid.set_header(col, header);
+        Ok(())
+    }
+
+    fn serialize_cell<'a>(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: impl Serialize + 'a,
+    ) -> Result<(), Self::Error> {
+        let value = CellValue::from_cell(row, col, cell)?;
+        self.grid.set_cell(row, col, value);
+        Ok(())
+    }
+}
+
+/// Escapes pipes and replaces line breaks so the text fits in a table cell.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut exporter = MarkdownExporter::new();
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.serialize_header(2, "Active").unwrap();
+        exporter.serialize_cell(0, 0, "Alice").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.serialize_cell(0, 2, true).unwrap();
+        exporter.serialize_cell(1, 0, "Bob").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        exporter.serialize_cell(1, 2, false).unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            concat!(
+                "| Name  | Age | Active |\n",
+                "| ----- | --: | ------ |\n",
+                "| Alice |  30 | true   |\n",
+                "| Bob   |     | false  |\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_alignments() {
+        let mut exporter = MarkdownExporter::new()
+            .with_alignment(0, Alignment::Left)
+            .with_alignment(1, Alignment::Center)
+            .with_alignment(2, Alignment::Right);
+        exporter.serialize_cell(0, 0, "a").unwrap();
+        exporter.serialize_cell(0, 1, "b").unwrap();
+        exporter.serialize_cell(0, 2, "c").unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            concat!(
+                "|     |     |     |\n",
+                "| :-- | :-: | --: |\n",
+                "| a   |  b  |   c |\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        let mut exporter = MarkdownExporter::new();
+        exporter.serialize_header(0, "a|b").unwrap();
+        exporter
+            .serialize_cell(0, 0, "line 1\nline 2\r\nline 3")
+            .unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            concat!(
+                "| a\\|b                       |\n",
+                "| -------------------------- |\n",
+                "| line 1<br>line 2<br>line 3 |\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let mut exporter = MarkdownExporter::new();
+        exporter.serialize_header(0, "A").unwrap();
+        exporter.serialize_cell(0, 1, "x").unwrap();
+        assert_eq!(
+            exporter.into_string(),
+            concat!("| A   |     |\n", "| --- | --- |\n", "|     | x   |\n")
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(MarkdownExporter::new().into_string(), "");
+    }
+
+    #[test]
+    fn test_nested_value_error() {
+        let mut exporter = MarkdownExporter::new();
+        let error = exporter.serialize_cell(1, 2, vec![1]).unwrap_err();
+        assert_eq!((error.row, error.col), (1, 2));
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+/// Chooses the columns and rows exported by
+/// [`TableContext::serialize_with_options`](crate::TableContext::serialize_with_options).
+///
+/// The default exports what the user sees: the visible columns in display order, and the rows
+/// that pass the filters and the search, in sorted order, across all pages.
+/// [`ExportOptions::everything`] exports all columns in definition order and all rows in
+/// original order.
+///
+/// Columns excluded with
+/// [`SerializableColumn::include_in_export`](crate::SerializableColumn::include_in_export)
+/// are never exported.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::ExportOptions;
+///
+/// // Only the selected rows, as displayed
+/// let options = ExportOptions::default().with_row_keys(["task-1", "task-7"]);
+/// assert!(options.includes_row("task-7"));
+/// assert!(!options.includes_row("task-2"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ExportOptions {
+    /// Export hidden columns too. Hidden columns follow the visible ones, in definition order.
+    pub all_columns: bool,
+    /// Order columns as defined in the columns tuple instead of as displayed.
+    pub definition_order: bool,
+    /// Export the rows that don't pass the filters or the search too.
+    pub all_rows: bool,
+    /// Keep the rows in their original order instead of sorting and grouping them.
+    pub original_order: bool,
+    /// Only export the rows with these keys, if set.
+    pub row_keys: Option<HashSet<String>>,
+}
+
+impl ExportOptions {
+    /// Returns options exporting all columns in definition order and all rows in original order.
+    pub fn everything() -> Self {
+        Self {
+            all_columns: true,
+            definition_order: true,
+            all_rows: true,
+            original_order: true,
+            row_keys: None,
+        }
+    }
+
+    /// Only exports the rows with the given keys, e.g. those of
+    /// [`TableData::selected_keys`](crate::TableData::selected_keys).
+    pub fn with_row_keys<K: Into<String>>(self, keys: impl IntoIterator<Item = K>) -> Self {
+        Self {
+            row_keys: Some(keys.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Returns whether the row with the given key passes the row key allowlist.
+    pub fn includes_row(&self, key: &str) -> bool {
+        self.row_keys.as_ref().is_none_or(|keys| keys.contains(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_includes_row() {
+        assert!(ExportOptions::default().includes_row("any"));
+
+        let options = ExportOptions::everything().with_row_keys(Vec::<String>::new());
+        assert!(!options.includes_row("any"));
+        assert!(options.all_columns && options.all_rows);
+    }
+}
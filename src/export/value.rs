@@ -0,0 +1,361 @@
+use serde::Serialize;
+use serde::ser::{self, Impossible};
+use std::fmt::{self, Display};
+
+/// A cell flattened to a single scalar value.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum CellValue {
+    /// `None`, `()` and unit structs.
+    Empty,
+    Bool(bool),
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    /// Strings, chars and unit enum variants.
+    Text(String),
+}
+
+impl CellValue {
+    /// Flattens a serializable value, failing for values that have no scalar form (sequences,
+    /// maps, structs, bytes and enum variants with data).
+    ///
+    /// `Some` and newtype structs are flattened to their inner value.
+    pub(crate) fn from_serialize(value: impl Serialize) -> Result<Self, ValueError> {
+        value.serialize(ValueSerializer)
+    }
+
+    /// Like [`from_serialize`](Self::from_serialize), reporting failures as a [`CellError`]
+    pub(crate) fn from_cell(
+        row: usize,
+        col: usize,
+        cell: impl Serialize,
+    ) -> Result<Self, CellError> {
+        Self::from_serialize(cell).map_err(|error| CellError {
+            row,
+            col,
+            message: error.to_string(),
+        })
+    }
+
+    /// Checks if this value is a number
+    pub(crate) fn is_number(&self) -> bool {
+        matches!(self, Self::Int(_) | Self::UInt(_) | Self::Float(_))
+    }
+}
+
+impl Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => Ok(()),
+            Self::Bool(value) => value.fmt(f),
+            Self::Int(value) => value.fmt(f),
+            Self::UInt(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+/// The error returned by exporters that write to memory, like [`MarkdownExporter`](crate::MarkdownExporter),
+/// when a cell cannot be represented as a single value, e.g. a sequence or a struct.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CellError {
+    /// The exported row index.
+    pub row: usize,
+    /// The exported column index.
+    pub col: usize,
+    /// Why the cell could not be written.
+    pub message: String,
+}
+
+impl Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid cell at row {}, column {}: {}",
+            self.row, self.col, self.message
+        )
+    }
+}
+
+impl std::error::Error for CellError {}
+
+/// The reason a value could not be flattened to a [`CellValue`].
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct ValueError(pub(crate) String);
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl ser::Error for ValueError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+fn unsupported(kind: &str) -> ValueError {
+    ValueError(format!("{kind} cannot be represented as a single value"))
+}
+
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = CellValue;
+    type Error = ValueError;
+    type SerializeSeq = Impossible<CellValue, ValueError>;
+    type SerializeTuple = Impossible<CellValue, ValueError>;
+    type SerializeTupleStruct = Impossible<CellValue, ValueError>;
+    type SerializeTupleVariant = Impossible<CellValue, ValueError>;
+    type SerializeMap = Impossible<CellValue, ValueError>;
+    type SerializeStruct = Impossible<CellValue, ValueError>;
+    type SerializeStructVariant = Impossible<CellValue, ValueError>;
+
+    fn serialize_bool(self, v: bool) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Int(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Int(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Int(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Int(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Int(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<CellValue, ValueError> {
+        Ok(CellValue::UInt(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<CellValue, ValueError> {
+        Ok(CellValue::UInt(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<CellValue, ValueError> {
+        Ok(CellValue::UInt(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<CellValue, ValueError> {
+        Ok(CellValue::UInt(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<CellValue, ValueError> {
+        Ok(CellValue::UInt(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Float(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Text(v.to_string()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<CellValue, ValueError> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Empty)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<CellValue, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Empty)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Empty)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<CellValue, ValueError> {
+        Ok(CellValue::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<CellValue, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<CellValue, ValueError> {
+        Err(unsupported(&format!("enum variant `{name}::{variant}`")))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, ValueError> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, ValueError> {
+        Err(unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, ValueError> {
+        Err(unsupported(&format!("tuple struct `{name}`")))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, ValueError> {
+        Err(unsupported(&format!("enum variant `{name}::{variant}`")))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, ValueError> {
+        Err(unsupported("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, ValueError> {
+        Err(unsupported(&format!("struct `{name}`")))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, ValueError> {
+        Err(unsupported(&format!("enum variant `{name}::{variant}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    struct Wrapper(u32);
+
+    #[derive(Serialize)]
+    struct Point {
+        x: i32,
+    }
+
+    #[derive(Serialize)]
+    enum Status {
+        Open,
+        Blocked(String),
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(CellValue::from_serialize(true), Ok(CellValue::Bool(true)));
+        assert_eq!(CellValue::from_serialize(-3i8), Ok(CellValue::Int(-3)));
+        assert_eq!(
+            CellValue::from_serialize(u64::MAX),
+            Ok(CellValue::UInt(u64::MAX.into()))
+        );
+        assert_eq!(CellValue::from_serialize(1.5f32), Ok(CellValue::Float(1.5)));
+        assert_eq!(
+            CellValue::from_serialize('x'),
+            Ok(CellValue::Text("x".into()))
+        );
+        assert_eq!(
+            CellValue::from_serialize("a \"b\""),
+            Ok(CellValue::Text("a \"b\"".into()))
+        );
+    }
+
+    #[test]
+    fn test_flattened() {
+        assert_eq!(CellValue::from_serialize(None::<u32>), Ok(CellValue::Empty));
+        assert_eq!(
+            CellValue::from_serialize(Some(2u32)),
+            Ok(CellValue::UInt(2))
+        );
+        assert_eq!(CellValue::from_serialize(()), Ok(CellValue::Empty));
+        assert_eq!(
+            CellValue::from_serialize(Wrapper(7)),
+            Ok(CellValue::UInt(7))
+        );
+        assert_eq!(
+            CellValue::from_serialize(Status::Open),
+            Ok(CellValue::Text("Open".into()))
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(
+            CellValue::from_serialize(vec![1, 2]),
+            Err(ValueError(
+                "a sequence cannot be represented as a single value".into()
+            ))
+        );
+        assert_eq!(
+            CellValue::from_serialize(Point { x: 1 }),
+            Err(ValueError(
+                "struct `Point` cannot be represented as a single value".into()
+            ))
+        );
+        assert_eq!(
+            CellValue::from_serialize(Status::Blocked("x".into())),
+            Err(ValueError(
+                "enum variant `Status::Blocked` cannot be represented as a single value".into()
+            ))
+        );
+        assert!(CellValue::from_serialize(HashMap::<String, u32>::new()).is_err());
+        assert!(CellValue::from_serialize((1, 2)).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(CellValue::Empty.to_string(), "");
+        assert_eq!(CellValue::Float(0.1).to_string(), "0.1");
+        assert_eq!(CellValue::Int(-5).to_string(), "-5");
+        assert_eq!(CellValue::Text("hi".into()).to_string(), "hi");
+    }
+}
@@ -0,0 +1,713 @@
+use super::grid::Grid;
+use super::value::CellValue;
+use super::zip::write_zip;
+use crate::Exporter;
+use serde::Serialize;
+use std::fmt::{self, Display, Write as _};
+use std::io::Write;
+
+/// The largest integer magnitude that a spreadsheet number (an `f64`) represents exactly.
+const MAX_EXACT_INTEGER: u128 = 1 << 53;
+/// The widest a column gets when sized to its content, in characters.
+const MAX_COLUMN_WIDTH: usize = 80;
+/// The longest sheet name allowed by Excel.
+const MAX_SHEET_NAME_LEN: usize = 31;
+/// The number of rows of an Excel worksheet.
+const MAX_ROWS: usize = 1 << 20;
+/// The number of columns of an Excel worksheet.
+const MAX_COLUMNS: usize = 1 << 14;
+/// The most characters Excel allows in a cell.
+const MAX_TEXT_LEN: usize = 32_767;
+
+/// The error returned by [`XlsxExporter`].
+#[derive(Debug)]
+pub enum XlsxError {
+    /// Writing to the output failed.
+    Io(std::io::Error),
+    /// A cell could not be represented as a single spreadsheet value, e.g. a sequence or a struct.
+    Cell {
+        /// The exported row index.
+        row: usize,
+        /// The exported column index.
+        col: usize,
+        /// Why the cell could not be written.
+        message: String,
+    },
+    /// A cell would lie beyond the 1,048,576 rows or 16,384 columns of a worksheet.
+    OutOfRange {
+        /// The worksheet row of the cell (0-indexed), counting the header row.
+        sheet_row: usize,
+        /// The exported column index.
+        col: usize,
+    },
+    /// A header or a cell holds more than the 32,767 characters allowed in a cell.
+    TextTooLong {
+        /// The worksheet row of the cell (0-indexed), counting the header row.
+        sheet_row: usize,
+        /// The exported column index.
+        col: usize,
+        /// The number of characters of the text.
+        len: usize,
+    },
+}
+
+impl Display for XlsxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to write XLSX: {error}"),
+            Self::Cell { row, col, message } => {
+                write!(f, "invalid cell at row {row}, column {col}: {message}")
+            }
+            Self::OutOfRange { sheet_row, col } => write!(
+                f,
+                "cell at worksheet row {sheet_row}, column {col} exceeds the worksheet size \
+                 of {MAX_ROWS} rows and {MAX_COLUMNS} columns"
+            ),
+            Self::TextTooLong {
+                sheet_row,
+                col,
+                len,
+            } => write!(
+                f,
+                "cell at worksheet row {sheet_row}, column {col} has {len} characters, more \
+                 than the {MAX_TEXT_LEN} allowed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for XlsxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Cell { .. } | Self::OutOfRange { .. } | Self::TextTooLong { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for XlsxError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// An [`Exporter`] writing an Excel workbook (`.xlsx`) with a single worksheet.
+///
+/// - The headers form a bold first row, frozen so it stays visible while scrolling.
+/// - Numbers and booleans are written as typed cells, and strings, chars and unit enum
+///   variants as text. `None` and `()` leave the cell blank, and `Some` and newtype structs
+///   are written as their inner value. Other values, like sequences or structs, fail with
+///   [`XlsxError::Cell`].
+/// - Integers that a spreadsheet cannot represent exactly (beyond ±2<sup>53</sup>) and
+///   non-finite floats are written as text.
+/// - Columns are sized to fit their content.
+/// - Cells beyond the 1,048,576 rows (header included) or 16,384 columns of a worksheet fail
+///   with [`XlsxError::OutOfRange`], and headers or cells longer than 32,767 characters with
+///   [`XlsxError::TextTooLong`].
+/// - The workbook is not written in the ZIP64 format, so workbooks over 4 GiB fail with
+///   [`XlsxError::Io`].
+///
+/// The workbook is built in memory and written by [`finish`](Exporter::finish), so export
+/// with [`TableContext::serialize`](crate::TableContext::serialize) and take the writer back
+/// with [`into_inner`](Self::into_inner).
+///
+/// Requires the `xlsx` feature.
+///
+/// # Example
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tabular::*;
+/// # use serde::Serialize;
+/// # #[derive(Clone, PartialEq)]
+/// # struct User { id: u32 }
+/// # impl Row for User {
+/// #     fn key(&self) -> impl Into<String> { self.id.to_string() }
+/// # }
+/// # #[derive(Clone, PartialEq)]
+/// # struct Col;
+/// # impl TableColumn<User> for Col {
+/// #     fn column_name(&self) -> String { "col".into() }
+/// #     fn render_header(&self, _: ColumnContext, _: Vec<Attribute>) -> Element { rsx! { th {} } }
+/// #     fn render_cell(&self, _: ColumnContext, _: &User, _: Vec<Attribute>) -> Element { rsx! { td {} } }
+/// # }
+/// # impl SerializableColumn<User> for Col {
+/// #     fn serialize_cell(&self, row: &User) -> impl Serialize + '_ { row.id }
+/// # }
+/// # fn export(data: TableData<(Col,), User>) -> Result<Vec<u8>, ExportError<XlsxError>> {
+/// let mut exporter = XlsxExporter::new(Vec::new()).with_sheet_name("Users");
+/// data.serialize(&mut exporter)?;
+/// let workbook = exporter.into_inner();
+/// # Ok(workbook)
+/// # }
+/// ```
+pub struct XlsxExporter<W: Write> {
+    writer: W,
+    sheet_name: String,
+    grid: Grid,
+}
+
+impl<W: Write> XlsxExporter<W> {
+    /// Creates a new XlsxExporter with a worksheet named `Sheet1`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            sheet_name: "Sheet1".to_string(),
+            grid: Grid::default(),
+        }
+    }
+
+    /// Sets the name of the worksheet.
+    ///
+    /// Characters not allowed by Excel (`[]:*?/\`) are replaced by `_`, and the name is
+    /// truncated to 31 characters.
+    pub fn with_sheet_name(self, name: impl Into<String>) -> Self {
+        let name: String = name
+            .into()
+            .chars()
+            .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+            .take(MAX_SHEET_NAME_LEN)
+            .collect();
+        Self {
+            sheet_name: if name.is_empty() {
+                "Sheet1".to_string()
+            } else {
+                name
+            },
+            ..self
+        }
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn sheet_xml(&self) -> String {
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+        ));
+        let has_headers = !self.grid.headers.is_empty();
+        if has_headers {
+            xml.push_str(concat!(
+                r#"<sheetViews><sheetView workbookViewId="0">"#,
+                r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#,
+                r#"</sheetView></sheetViews>"#,
+            ));
+        }
+
+        let widths = self.column_widths();
+        if !widths.is_empty() {
+            xml.push_str("<cols>");
+            for (col, width) in widths.iter().enumerate() {
+                let n = col + 1;
+                let _ = write!(
+                    xml,
+                    r#"<col min="{n}" max="{n}" width="{width}" customWidth="1"/>"#
+                );
+            }
+            xml.push_str("</cols>");
+        }
+
+        xml.push_str("<sheetData>");
+        let mut sheet_row = 0;
+        if has_headers {
+            sheet_row += 1;
+            let _ = write!(xml, r#"<row r="{sheet_row}">"#);
+            for (col, header) in self.grid.headers.iter().enumerate() {
+                // Style 1 is bold
+                let _ = write!(
+                    xml,
+                    r#"<c r="{}{sheet_row}" s="1" t="inlineStr">{}</c>"#,
+                    column_letters(col),
+                    inline_string(header)
+                );
+            }
+            xml.push_str("</row>");
+        }
+        for cells in &self.grid.rows {
+            sheet_row += 1;
+            let _ = write!(xml, r#"<row r="{sheet_row}">"#);
+            for (col, value) in cells.iter().enumerate() {
+                let reference = format!("{}{sheet_row}", column_letters(col));
+                match typed(value) {
+                    Typed::Blank => {}
+                    Typed::Bool(value) => {
+                        let _ = write!(
+                            xml,
+                            r#"<c r="{reference}" t="b"><v>{}</v></c>"#,
+                            value as u8
+                        );
+                    }
+                    Typed::Number(value) => {
+                        let _ = write!(xml, r#"<c r="{reference}"><v>{value}</v></c>"#);
+                    }
+                    Typed::Text(value) => {
+                        let _ = write!(
+                            xml,
+                            r#"<c r="{reference}" t="inlineStr">{}</c>"#,
+                            inline_string(&value)
+                        );
+                    }
+                }
+            }
+            xml.push_str("</row>");
+        }
+        xml.push_str("</sheetData></worksheet>");
+        xml
+    }
+
+    /// Returns the width of each column in characters, fitting its longest value.
+    fn column_widths(&self) -> Vec<usize> {
+        (0..self.grid.column_count())
+            .map(|col| {
+                let header = self.grid.headers.get(col).map_or(0, |h| h.chars().count());
+                let longest = self
+                    .grid
+                    .column(col)
+                    .map(|value| match value {
+                        CellValue::Bool(true) => 4,
+                        CellValue::Bool(false) => 5,
+                        value => value.to_string().chars().count(),
+                    })
+                    .fold(header, usize::max);
+                (longest + 2).min(MAX_COLUMN_WIDTH)
+            })
+            .collect()
+    }
+}
+
+impl<W: Write> Exporter for XlsxExporter<W> {
+    type Error = XlsxError;
+
+    fn serialize_header(&mut self, col: usize, header: &str) -> Result<(), Self::Error> {
+        check_range(0, col)?;
+        check_text(0, col, header)?;
+        self.grid.set_header(col, header);
+        Ok(())
+    }
+
+    fn serialize_cell<'a>(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: impl Serialize + 'a,
+    ) -> Result<(), Self::Error> {
+        // The header row, if any, comes first
+        let sheet_row = row.saturating_add(usize::from(!self.grid.headers.is_empty()));
+        check_range(sheet_row, col)?;
+        let value = CellValue::from_serialize(cell).map_err(|error| XlsxError::Cell {
+            row,
+            col,
+            message: error.to_string(),
+        })?;
+        if let CellValue::Text(text) = &value {
+            check_text(sheet_row, col, text)?;
+        }
+        self.grid.set_cell(row, col, value);
+        Ok(())
+    }
+
+    /// Writes the workbook and flushes the writer.
+    fn finish(&mut self) -> Result<(), Self::Error> {
+        let workbook = format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" "#,
+                r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">"#,
+                r#"<sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+            ),
+            escape(&self.sheet_name)
+        );
+        let sheet = self.sheet_xml();
+        write_zip(
+            &mut self.writer,
+            &[
+                ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+                ("_rels/.rels", ROOT_RELS.as_bytes()),
+                ("xl/workbook.xml", workbook.as_bytes()),
+                ("xl/_rels/workbook.xml.rels", WORKBOOK_RELS.as_bytes()),
+                ("xl/styles.xml", STYLES.as_bytes()),
+                ("xl/worksheets/sheet1.xml", sheet.as_bytes()),
+            ],
+        )?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Fails if the cell at `sheet_row` and `col` doesn't fit in a worksheet.
+fn check_range(sheet_row: usize, col: usize) -> Result<(), XlsxError> {
+    if sheet_row < MAX_ROWS && col < MAX_COLUMNS {
+        Ok(())
+    } else {
+        Err(XlsxError::OutOfRange { sheet_row, col })
+    }
+}
+
+/// Fails if `text` is too long for the cell at `sheet_row` and `col`.
+fn check_text(sheet_row: usize, col: usize, text: &str) -> Result<(), XlsxError> {
+    let len = text.chars().count();
+    if len <= MAX_TEXT_LEN {
+        Ok(())
+    } else {
+        Err(XlsxError::TextTooLong {
+            sheet_row,
+            col,
+            len,
+        })
+    }
+}
+
+/// A cell value as stored in the worksheet.
+enum Typed {
+    Blank,
+    Bool(bool),
+    Number(String),
+    Text(String),
+}
+
+fn typed(value: &CellValue) -> Typed {
+    match value {
+        CellValue::Empty => Typed::Blank,
+        CellValue::Bool(value) => Typed::Bool(*value),
+        CellValue::Int(value) if value.unsigned_abs() <= MAX_EXACT_INTEGER => {
+            Typed::Number(value.to_string())
+        }
+        CellValue::UInt(value) if *value <= MAX_EXACT_INTEGER => Typed::Number(value.to_string()),
+        CellValue::Float(value) if value.is_finite() => Typed::Number(value.to_string()),
+        value => Typed::Text(value.to_string()),
+    }
+}
+
+/// Returns the letters of a zero-based column index: A, B, ..., Z, AA, AB, ...
+fn column_letters(col: usize) -> String {
+    let mut letters = Vec::new();
+    let mut n = col + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push(b'A' + rem as u8);
+        n = (n - 1) / 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+fn inline_string(text: &str) -> String {
+    if text.starts_with(char::is_whitespace) || text.ends_with(char::is_whitespace) {
+        format!(r#"<is><t xml:space="preserve">{}</t></is>"#, escape(text))
+    } else {
+        format!("<is><t>{}</t></is>", escape(text))
+    }
+}
+
+/// Escapes XML special characters and drops the control characters XML cannot contain.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c < ' ' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const CONTENT_TYPES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+    r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+    r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+    r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+    r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+    r#"<Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>"#,
+    r#"</Types>"#,
+);
+
+const ROOT_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+    r#"</Relationships>"#,
+);
+
+const WORKBOOK_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+    r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>"#,
+    r#"</Relationships>"#,
+);
+
+/// Style 0 is the default, style 1 uses the bold font.
+const STYLES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+    r#"<fonts count="2"><font><sz val="11"/><name val="Calibri"/></font>"#,
+    r#"<font><b/><sz val="11"/><name val="Calibri"/></font></fonts>"#,
+    r#"<fills count="2"><fill><patternFill patternType="none"/></fill>"#,
+    r#"<fill><patternFill patternType="gray125"/></fill></fills>"#,
+    r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+    r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+    r#"<cellXfs count="2"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>"#,
+    r#"<xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/></cellXfs>"#,
+    r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles>"#,
+    r#"</styleSheet>"#,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::super::zip::read_zip;
+    use super::*;
+
+    fn read_file(workbook: &[u8], name: &str) -> String {
+        let (_, data) = read_zip(workbook)
+            .into_iter()
+            .find(|(file, _)| file == name)
+            .unwrap_or_else(|| panic!("missing {name}"));
+        String::from_utf8(data).unwrap()
+    }
+
+    #[test]
+    fn test_workbook_files() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        exporter.finish().unwrap();
+        let workbook = exporter.into_inner();
+        let names: Vec<String> = read_zip(&workbook)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "[Content_Types].xml",
+                "_rels/.rels",
+                "xl/workbook.xml",
+                "xl/_rels/workbook.xml.rels",
+                "xl/styles.xml",
+                "xl/worksheets/sheet1.xml",
+            ]
+        );
+        assert!(read_file(&workbook, "xl/workbook.xml").contains(r#"<sheet name="Sheet1""#));
+    }
+
+    #[test]
+    fn test_typed_cells() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.serialize_header(2, "Active").unwrap();
+        exporter.serialize_cell(0, 0, "Alice & Bob").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.serialize_cell(0, 2, true).unwrap();
+        exporter.serialize_cell(1, 0, " padded").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        exporter.serialize_cell(1, 2, 2.5).unwrap();
+        exporter.finish().unwrap();
+        let workbook = exporter.into_inner();
+
+        let sheet = read_file(&workbook, "xl/worksheets/sheet1.xml");
+        let data = &sheet[sheet.find("<sheetData>").unwrap()..];
+        assert_eq!(
+            data,
+            concat!(
+                "<sheetData>",
+                r#"<row r="1">"#,
+                r#"<c r="A1" s="1" t="inlineStr"><is><t>Name</t></is></c>"#,
+                r#"<c r="B1" s="1" t="inlineStr"><is><t>Age</t></is></c>"#,
+                r#"<c r="C1" s="1" t="inlineStr"><is><t>Active</t></is></c>"#,
+                "</row>",
+                r#"<row r="2">"#,
+                r#"<c r="A2" t="inlineStr"><is><t>Alice &amp; Bob</t></is></c>"#,
+                r#"<c r="B2"><v>30</v></c>"#,
+                r#"<c r="C2" t="b"><v>1</v></c>"#,
+                "</row>",
+                r#"<row r="3">"#,
+                r#"<c r="A3" t="inlineStr"><is><t xml:space="preserve"> padded</t></is></c>"#,
+                r#"<c r="C3"><v>2.5</v></c>"#,
+                "</row>",
+                "</sheetData></worksheet>",
+            )
+        );
+    }
+
+    #[test]
+    fn test_frozen_header_and_widths() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Note").unwrap();
+        exporter.serialize_cell(0, 0, "Alexandra").unwrap();
+        exporter.serialize_cell(0, 1, "x".repeat(200)).unwrap();
+        exporter.finish().unwrap();
+        let workbook = exporter.into_inner();
+
+        let sheet = read_file(&workbook, "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains(
+            r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#
+        ));
+        assert!(sheet.contains(concat!(
+            "<cols>",
+            r#"<col min="1" max="1" width="11" customWidth="1"/>"#,
+            r#"<col min="2" max="2" width="80" customWidth="1"/>"#,
+            "</cols>"
+        )));
+    }
+
+    #[test]
+    fn test_no_frozen_pane_without_headers() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        exporter.serialize_cell(0, 0, 1).unwrap();
+        exporter.finish().unwrap();
+        let workbook = exporter.into_inner();
+        let sheet = read_file(&workbook, "xl/worksheets/sheet1.xml");
+        assert!(!sheet.contains("<pane"));
+        assert!(sheet.contains(r#"<row r="1"><c r="A1"><v>1</v></c></row>"#));
+    }
+
+    #[test]
+    fn test_inexact_numbers_are_text() {
+        assert!(matches!(
+            typed(&CellValue::Int(-(1 << 53))),
+            Typed::Number(_)
+        ));
+        assert!(matches!(
+            typed(&CellValue::UInt(u64::MAX.into())),
+            Typed::Text(_)
+        ));
+        assert!(matches!(typed(&CellValue::Float(f64::NAN)), Typed::Text(_)));
+    }
+
+    #[test]
+    fn test_column_letters() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn test_sheet_name() {
+        let mut exporter =
+            XlsxExporter::new(Vec::new()).with_sheet_name("Q1/Q2 <report> with a very long name");
+        exporter.finish().unwrap();
+        let workbook = exporter.into_inner();
+        assert!(
+            read_file(&workbook, "xl/workbook.xml")
+                .contains(r#"<sheet name="Q1_Q2 &lt;report&gt; with a very long""#)
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("a<b>\"c\"&\u{1}d\n"),
+            "a&lt;b&gt;&quot;c&quot;&amp;d\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_value_error() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        let error = exporter.serialize_cell(2, 1, vec!["a"]).unwrap_err();
+        assert!(matches!(error, XlsxError::Cell { row: 2, col: 1, .. }));
+    }
+
+    #[test]
+    fn test_worksheet_limits() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        let error = exporter.serialize_cell(MAX_ROWS, 0, 1).unwrap_err();
+        assert!(
+            matches!(error, XlsxError::OutOfRange { sheet_row, col: 0 } if sheet_row == MAX_ROWS)
+        );
+        let error = exporter.serialize_cell(0, MAX_COLUMNS, 1).unwrap_err();
+        assert!(matches!(error, XlsxError::OutOfRange { sheet_row: 0, col } if col == MAX_COLUMNS));
+        let error = exporter.serialize_header(MAX_COLUMNS, "Extra").unwrap_err();
+        assert!(matches!(error, XlsxError::OutOfRange { sheet_row: 0, col } if col == MAX_COLUMNS));
+
+        // The header row moves the exported rows down by one
+        exporter.serialize_header(MAX_COLUMNS - 1, "Last").unwrap();
+        let error = exporter.serialize_cell(MAX_ROWS - 1, 0, 1).unwrap_err();
+        assert!(
+            matches!(error, XlsxError::OutOfRange { sheet_row, col: 0 } if sheet_row == MAX_ROWS)
+        );
+    }
+
+    #[test]
+    fn test_text_length_limit() {
+        let mut exporter = XlsxExporter::new(Vec::new());
+        exporter.serialize_header(0, "Notes").unwrap();
+        exporter
+            .serialize_cell(0, 0, "é".repeat(MAX_TEXT_LEN))
+            .unwrap();
+        let error = exporter
+            .serialize_cell(1, 0, "x".repeat(MAX_TEXT_LEN + 1))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XlsxError::TextTooLong { sheet_row: 2, col: 0, len } if len == MAX_TEXT_LEN + 1
+        ));
+        let error = exporter
+            .serialize_header(1, &"x".repeat(MAX_TEXT_LEN + 1))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XlsxError::TextTooLong {
+                sheet_row: 0,
+                col: 1,
+                ..
+            }
+        ));
+        exporter.finish().unwrap();
+    }
+
+    #[test]
+    fn test_read_back_with_calamine() {
+        use calamine::{Data, Reader, Xlsx};
+
+        let mut exporter = XlsxExporter::new(Vec::new()).with_sheet_name("People");
+        exporter.serialize_header(0, "Name").unwrap();
+        exporter.serialize_header(1, "Age").unwrap();
+        exporter.serialize_header(2, "Active").unwrap();
+        exporter.serialize_cell(0, 0, "Alice & <Bob>").unwrap();
+        exporter.serialize_cell(0, 1, 30).unwrap();
+        exporter.serialize_cell(0, 2, true).unwrap();
+        exporter.serialize_cell(1, 0, " padded").unwrap();
+        exporter.serialize_cell(1, 1, None::<u32>).unwrap();
+        exporter.serialize_cell(1, 2, 2.5).unwrap();
+        exporter.serialize_cell(2, 0, u64::MAX).unwrap();
+        exporter.finish().unwrap();
+
+        let mut workbook = Xlsx::new(std::io::Cursor::new(exporter.into_inner())).unwrap();
+        assert_eq!(workbook.sheet_names(), ["People"]);
+        let range = workbook.worksheet_range("People").unwrap();
+        let rows: Vec<Vec<Data>> = range.rows().map(|row| row.to_vec()).collect();
+        assert_eq!(
+            rows,
+            [
+                vec![
+                    Data::String("Name".into()),
+                    Data::String("Age".into()),
+                    Data::String("Active".into()),
+                ],
+                vec![
+                    Data::String("Alice & <Bob>".into()),
+                    Data::Float(30.0),
+                    Data::Bool(true),
+                ],
+                vec![
+                    Data::String(" padded".into()),
+                    Data::Empty,
+                    Data::Float(2.5)
+                ],
+                vec![Data::String(u64::MAX.to_string()), Data::Empty, Data::Empty],
+            ]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! A minimal ZIP archive writer, enough for the files of an XLSX workbook.
+
+use flate2::Compression;
+use flate2::Crc;
+use flate2::write::DeflateEncoder;
+use std::io::{self, Write};
+
+/// The DOS date of 1980-01-01, the earliest date a ZIP entry can have.
+const DOS_DATE: u16 = (1 << 5) | 1;
+/// The "version needed to extract" of deflated entries (2.0).
+const VERSION: u16 = 20;
+/// The deflate compression method.
+const DEFLATE: u16 = 8;
+
+struct CentralEntry<'a> {
+    name: &'a str,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Writes a ZIP archive containing the given files, deflated, in order.
+///
+/// Timestamps are fixed so the same files always produce the same archive.
+pub(crate) fn write_zip(writer: &mut impl Write, files: &[(&str, &[u8])]) -> io::Result<()> {
+    let mut offset = 0u32;
+    let mut entries = Vec::with_capacity(files.len());
+    for &(name, data) in files {
+        let mut crc = Crc::new();
+        crc.update(data);
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data)?;
+        let compressed = encoder.finish()?;
+        let entry = CentralEntry {
+            name,
+            crc: crc.sum(),
+            compressed_size: to_u32(compressed.len())?,
+            size: to_u32(data.len())?,
+            offset,
+        };
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend(0x04034b50u32.to_le_bytes());
+        header.extend(VERSION.to_le_bytes());
+        header.extend(0u16.to_le_bytes()); // flags
+        header.extend(DEFLATE.to_le_bytes());
+        header.extend(0u16.to_le_bytes()); // time
+        header.extend(DOS_DATE.to_le_bytes());
+        header.extend(entry.crc.to_le_bytes());
+        header.extend(entry.compressed_size.to_le_bytes());
+        header.extend(entry.size.to_le_bytes());
+        header.extend(to_u16(name.len())?.to_le_bytes());
+        header.extend(0u16.to_le_bytes()); // extra field length
+        header.extend(name.as_bytes());
+        writer.write_all(&header)?;
+        writer.write_all(&compressed)?;
+
+        offset = to_u32(offset as usize + header.len() + compressed.len())?;
+        entries.push(entry);
+    }
+
+    let central_offset = offset;
+    let mut central = Vec::new();
+    for entry in &entries {
+        central.extend(0x02014b50u32.to_le_bytes());
+        central.extend(VERSION.to_le_bytes()); // version made by
+        central.extend(VERSION.to_le_bytes());
+        central.extend(0u16.to_le_bytes()); // flags
+        central.extend(DEFLATE.to_le_bytes());
+        central.extend(0u16.to_le_bytes()); // time
+        central.extend(DOS_DATE.to_le_bytes());
+        central.extend(entry.crc.to_le_bytes());
+        central.extend(entry.compressed_size.to_le_bytes());
+        central.extend(entry.size.to_le_bytes());
+        central.extend(to_u16(entry.name.len())?.to_le_bytes());
+        central.extend(0u16.to_le_bytes()); // extra field length
+        central.extend(0u16.to_le_bytes()); // comment length
+        central.extend(0u16.to_le_bytes()); // disk number
+        central.extend(0u16.to_le_bytes()); // internal attributes
+        central.extend(0u32.to_le_bytes()); // external attributes
+        central.extend(entry.offset.to_le_bytes());
+        central.extend(entry.name.as_bytes());
+    }
+    writer.write_all(&central)?;
+
+    let count = to_u16(entries.len())?;
+    let mut end = Vec::with_capacity(22);
+    end.extend(0x06054b50u32.to_le_bytes());
+    end.extend(0u16.to_le_bytes()); // disk number
+    end.extend(0u16.to_le_bytes()); // disk with the central directory
+    end.extend(count.to_le_bytes());
+    end.extend(count.to_le_bytes());
+    end.extend(to_u32(central.len())?.to_le_bytes());
+    end.extend(central_offset.to_le_bytes());
+    end.extend(0u16.to_le_bytes()); // comment length
+    writer.write_all(&end)
+}
+
+fn to_u32(value: usize) -> io::Result<u32> {
+    u32::try_from(value).map_err(|_| io::Error::other("archive exceeds the 4 GiB ZIP limit"))
+}
+
+fn to_u16(value: usize) -> io::Result<u16> {
+    u16::try_from(value).map_err(|_| io::Error::other("too many or too long ZIP entries"))
+}
+
+/// Reads the files of an archive written by [`write_zip`].
+#[cfg(test)]
+pub(crate) fn read_zip(archive: &[u8]) -> Vec<(String, Vec<u8>)> {
+    use std::io::Read;
+
+    let u16_at = |at: usize| u16::from_le_bytes([archive[at], archive[at + 1]]) as usize;
+    let u32_at = |at: usize| u32::from_le_bytes(archive[at..at + 4].try_into().unwrap());
+
+    let mut files = Vec::new();
+    let mut at = 0;
+    while u32_at(at) == 0x04034b50 {
+        assert_eq!(u16_at(at + 8), DEFLATE as usize);
+        let crc = u32_at(at + 14);
+        let compressed_size = u32_at(at + 18) as usize;
+        let name_len = u16_at(at + 26);
+        let data_start = at + 30 + name_len + u16_at(at + 28);
+        let name = String::from_utf8(archive[at + 30..at + 30 + name_len].to_vec()).unwrap();
+
+        let mut data = Vec::new();
+        flate2::read::DeflateDecoder::new(&archive[data_start..data_start + compressed_size])
+            .read_to_end(&mut data)
+            .unwrap();
+        let mut actual = Crc::new();
+        actual.update(&data);
+        assert_eq!(actual.sum(), crc, "CRC mismatch in {name}");
+
+        files.push((name, data));
+        at = data_start + compressed_size;
+    }
+    assert_eq!(u32_at(at), 0x02014b50, "missing central directory");
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut archive = Vec::new();
+        let big = "abc".repeat(1000);
+        write_zip(
+            &mut archive,
+            &[
+                ("a.txt", b"hello"),
+                ("dir/b.xml", big.as_bytes()),
+                ("empty", b""),
+            ],
+        )
+        .unwrap();
+
+        let files = read_zip(&archive);
+        assert_eq!(
+            files,
+            [
+                ("a.txt".to_string(), b"hello".to_vec()),
+                ("dir/b.xml".to_string(), big.into_bytes()),
+                ("empty".to_string(), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_end_of_central_directory() {
+        let mut archive = Vec::new();
+        write_zip(&mut archive, &[("a", b"1"), ("b", b"2")]).unwrap();
+
+        let end = &archive[archive.len() - 22..];
+        assert_eq!(&end[..4], &0x06054b50u32.to_le_bytes());
+        // Two entries on this disk and in total
+        assert_eq!(&end[8..12], &[2, 0, 2, 0]);
+        let central_offset = u32::from_le_bytes(end[16..20].try_into().unwrap()) as usize;
+        assert_eq!(
+            &archive[central_offset..central_offset + 4],
+            &0x02014b50u32.to_le_bytes()
+        );
+    }
+}
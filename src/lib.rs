@@ -10,6 +10,8 @@
 //! - **[`GetRowData<T>`]**: Trait providing typed access to row data
 //! - **[`TableColumn`]**: Trait describing how a column renders, filters, and sorts
 //! - **[`Columns`]**: Automatically implemented for tuples of `TableColumn`s
+//! - **[`IntoColumns`]**: Builds `Columns` from tuples of more than 12 columns or of column sets
+//! - **[`DynTableColumn`]**: Object-safe column, for `Vec`s of columns defined at runtime
 //! - **[`use_tabular`]**: Hook to create a reactive table
 //! - **[`TableHeaders`]** / **[`TableCells`]**: Components for rendering headers and cells
 //!
@@ -94,14 +96,95 @@
 //!
 //! Columns can implement filtering logic via [`TableColumn::filter`].
 //! Filters are automatically applied when rendering rows.
+//! By default a row must pass all column filters; use [`TableData::set_filter_expr`] with a
+//! [`FilterExpr`] to combine them with OR, NOT and nested groups instead.
+//!
+//! ## Global Search
+//!
+//! [`TableData::set_search_query`] keeps the rows where the [`TableColumn::search_text`] of any
+//! visible column contains the query, combined with the column filters.
+//! Matching ignores case and diacritics by default; see [`SearchOptions`].
 //!
 //! ## Column Ordering and Visibility
 //!
 //! Control which columns are displayed and in what order using methods on [`ColumnContext`]:
 //! - `hide()` / `show()` - Toggle column visibility
 //! - `move_to()`, `move_forward()`, `move_backward()` - Reorder columns
+//! - `pin_left()`, `pin_right()`, `unpin()` - Keep columns in place while scrolling horizontally
 //! - `reset_order()` - Restore default state
 //!
+//! Pinned columns are rendered with `position: sticky`, offset by the widths of the columns
+//! pinned before them.
+//!
+//! ## Column Groups
+//!
+//! [`ColumnGroups`] assigns columns to named groups, and [`TableColumnGroups`] renders them as an
+//! extra header row whose `colspan`s follow the current column order and visibility.
+//!
+//! ## Column Widths
+//!
+//! Columns returning a [`default_width`](TableColumn::default_width) get a `width` style on
+//! their headers, cells and footers. Change it with `ColumnContext::set_width()`, within the
+//! [`min_width`](TableColumn::min_width) and [`max_width`](TableColumn::max_width) of the
+//! column, or let users drag a [`ColumnResizeHandle`] placed in the header.
+//!
+//! ## Column Footers
+//!
+//! Implement [`TableColumn::render_footer`] to aggregate the filtered rows (totals, averages, counts, ...)
+//! and render them with [`TableFooters`], which follows the column order and visibility.
+//!
+//! ## Pagination
+//!
+//! Call [`TableData::set_page_size`] to paginate the filtered and sorted rows.
+//! [`TableData::rows`] then yields only the current page, navigated with
+//! `next_page()`, `prev_page()` and `go_to_page()`.
+//! The page is clamped when filters shrink the result and reset when the sort changes.
+//!
+//! ## Row Selection
+//!
+//! Rows are selected by [`Row::key`], so the selection survives re-sorting and filtering.
+//! Use [`RowData::toggle_selected`] or [`RowData::select`] with a [`SelectionGesture`]
+//! (`Replace`, `Toggle`, or `Range` for Shift-click over the current sorted order), and
+//! `select_all_visible()`, `invert_selection()` or `clear_selection()` on [`TableData`].
+//! [`SelectionMode::Single`] restricts the selection to one row.
+//!
+//! ## Expandable Detail Rows
+//!
+//! Rows can be expanded with [`RowData::toggle_expanded`] to show a [`TableDetailRow`] below them.
+//! Like the selection, the expanded state is keyed by [`Row::key`] and survives re-sorts and filters.
+//!
+//! ## Row Grouping
+//!
+//! Columns implementing [`TableColumn::group_key`] can group rows with [`TableData::set_group_by`].
+//! [`TableData::grouped_rows`] yields a [`TableItem`] for each group header and row, and
+//! [`GroupHeaderData::toggle_collapsed`] hides the rows of a group. Sorts apply within each group.
+//!
+//! ## Virtualized Rendering
+//!
+//! For large datasets, [`VirtualTableBody`] renders only the rows inside the scroll viewport
+//! (plus spacer rows for the rest) using [`TableData::rows_in_range`].
+//! Rows must have a fixed height; see [`VirtualWindow`] for the underlying computation.
+//!
+//! ## Saving Table Layouts
+//!
+//! [`TableContext::snapshot`] captures the sorts, column order, visibility, pins and widths as a
+//! [`TableState`] keyed by column name, and [`TableContext::restore`] applies it again, tolerating columns that
+//! were added or removed in the meantime. Enable the `serde` feature to serialize it:
+//!
+//! ```toml
+//! dioxus-tabular = { version = "0.1", features = ["serde"] }
+//! ```
+//!
+//! For shareable links, [`TableContext::to_query_string`] encodes the same state as a compact
+//! query string like `sort=-priority,title&cols=title,status`, and
+//! [`TableContext::apply_query_string`] applies it back (see [`TableQuery`]).
+//!
+//! To remember layouts automatically, create the table with [`use_tabular_with_storage`] and a
+//! [`TableStorage`]: the layout is loaded on mount and saved on every change, with a column
+//! resize saved once when it ends. [`MemoryStorage`] is always available; the `file-storage`
+//! feature adds `JsonFileStorage` for desktop apps, and the `local-storage` feature adds
+//! `LocalStorage` for web apps.
+//!
 //! ## Export (optional feature)
 //!
 //! Enable the `export` feature to serialize table data:
@@ -111,14 +194,28 @@
 //! ```
 //!
 //! Implement [`SerializableColumn`] and use the [`Exporter`] trait to export to various formats.
+//! [`CsvExporter`] (RFC 4180 CSV) and [`JsonExporter`] (JSON or newline-delimited JSON) are built
+//! in and write to any [`std::io::Write`]. [`MarkdownExporter`] and [`HtmlExporter`] render the
+//! table as a string for pasting into issues and emails.
+//! Use [`ExportOptions`] to export hidden columns, unfiltered or unsorted rows, or only some rows.
+//! [`TableData::serialize_chunked`] exports large tables in chunks from an async task, with
+//! progress reporting and cancellation (see [`ChunkedExport`]).
+//! Columns defined at runtime are exported by boxing them as [`DynSerializableColumn`]s.
+//! Errors are wrapped in an [`ExportError`] naming the row and column that failed.
+//! Exporters can also implement the optional lifecycle callbacks of [`Exporter`], like
+//! [`Exporter::begin_row`] and [`Exporter::finish`], to frame rows and complete the output.
+//! Enable the `xlsx` feature for `XlsxExporter`, which writes Excel workbooks with typed cells.
 
 mod column;
 mod columns;
 mod components;
 mod context;
+mod dyn_column;
 #[cfg(feature = "export")]
 mod export;
 mod row;
+mod storage;
+mod virtualization;
 
 #[cfg(test)]
 pub mod test_suite;
@@ -127,6 +224,9 @@ pub use column::*;
 pub use columns::*;
 pub use components::*;
 pub use context::*;
+pub use dyn_column::*;
 #[cfg(feature = "export")]
 pub use export::*;
 pub use row::*;
+pub use storage::*;
+pub use virtualization::*;
@@ -0,0 +1,219 @@
+use crate::TableState;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A backend persisting table layouts across sessions.
+///
+/// Used by [`use_tabular_with_storage`](crate::use_tabular_with_storage), which loads the
+/// [`TableState`] of a table once on mount and saves it whenever its sorts or column layout change.
+///
+/// Layouts are a convenience, so implementations should handle their own failures, e.g. by
+/// loading `None` when the stored layout is missing or unreadable.
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::{MemoryStorage, TableState, TableStorage};
+///
+/// let storage = MemoryStorage::new();
+/// assert_eq!(storage.load("users"), None);
+///
+/// storage.save("users", &TableState::default());
+/// assert_eq!(storage.load("users"), Some(TableState::default()));
+/// ```
+pub trait TableStorage: 'static {
+    /// Loads the layout saved for the table `table_id`, if any.
+    fn load(&self, table_id: &str) -> Option<TableState>;
+
+    /// Saves the layout of the table `table_id`, replacing any previous one.
+    fn save(&self, table_id: &str, state: &TableState);
+}
+
+/// A [`TableStorage`] keeping layouts in memory for the lifetime of the app.
+///
+/// Clones share the same layouts, so one storage can be passed to several tables.
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+    states: Rc<RefCell<HashMap<String, TableState>>>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty MemoryStorage
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TableStorage for MemoryStorage {
+    fn load(&self, table_id: &str) -> Option<TableState> {
+        self.states.borrow().get(table_id).cloned()
+    }
+
+    fn save(&self, table_id: &str, state: &TableState) {
+        self.states
+            .borrow_mut()
+            .insert(table_id.to_string(), state.clone());
+    }
+}
+
+#[cfg(feature = "file-storage")]
+mod file {
+    use super::TableStorage;
+    use crate::TableState;
+    use std::path::PathBuf;
+
+    /// A [`TableStorage`] writing each layout as a JSON file in a directory, for desktop apps.
+    ///
+    /// The layout of table `table_id` is stored in `<dir>/<table_id>.json`, where characters
+    /// other than ASCII letters, digits, `-` and `_` in the id are replaced by `_`.
+    /// Unreadable files load as `None`, and write failures are ignored.
+    ///
+    /// Requires the `file-storage` feature.
+    #[derive(Clone, Debug)]
+    pub struct JsonFileStorage {
+        dir: PathBuf,
+    }
+
+    impl JsonFileStorage {
+        /// Creates a storage in the given directory, which is created on the first save.
+        pub fn new(dir: impl Into<PathBuf>) -> Self {
+            Self { dir: dir.into() }
+        }
+
+        /// Returns the path of the file storing the layout of `table_id`.
+        pub fn path(&self, table_id: &str) -> PathBuf {
+            let file_name: String = table_id
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            self.dir.join(format!("{file_name}.json"))
+        }
+    }
+
+    impl TableStorage for JsonFileStorage {
+        fn load(&self, table_id: &str) -> Option<TableState> {
+            let json = std::fs::read_to_string(self.path(table_id)).ok()?;
+            serde_json::from_str(&json).ok()
+        }
+
+        fn save(&self, table_id: &str, state: &TableState) {
+            let Ok(json) = serde_json::to_string_pretty(state) else {
+                return;
+            };
+            if std::fs::create_dir_all(&self.dir).is_ok() {
+                let _ = std::fs::write(self.path(table_id), json);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "file-storage")]
+pub use file::JsonFileStorage;
+
+#[cfg(feature = "local-storage")]
+mod local {
+    use super::TableStorage;
+    use crate::TableState;
+
+    /// A [`TableStorage`] keeping layouts as JSON in the browser's `localStorage`, for web apps.
+    ///
+    /// The layout of table `table_id` is stored under the key `<prefix><table_id>`.
+    /// If `localStorage` is unavailable, nothing is loaded or saved.
+    ///
+    /// Requires the `local-storage` feature and a `wasm32` target.
+    #[derive(Clone, Debug)]
+    pub struct LocalStorage {
+        prefix: String,
+    }
+
+    impl LocalStorage {
+        /// Creates a storage using keys starting with `prefix`, e.g. `"my-app.tables."`.
+        pub fn new(prefix: impl Into<String>) -> Self {
+            Self {
+                prefix: prefix.into(),
+            }
+        }
+
+        fn storage() -> Option<web_sys::Storage> {
+            web_sys::window()?.local_storage().ok()?
+        }
+    }
+
+    impl TableStorage for LocalStorage {
+        fn load(&self, table_id: &str) -> Option<TableState> {
+            let key = format!("{}{table_id}", self.prefix);
+            let json = Self::storage()?.get_item(&key).ok()??;
+            serde_json::from_str(&json).ok()
+        }
+
+        fn save(&self, table_id: &str, state: &TableState) {
+            let key = format!("{}{table_id}", self.prefix);
+            if let (Some(storage), Ok(json)) = (Self::storage(), serde_json::to_string(state)) {
+                let _ = storage.set_item(&key, &json);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "local-storage")]
+pub use local::LocalStorage;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SortDirection, SortState};
+
+    fn state(column: &str) -> TableState {
+        TableState {
+            sorts: vec![SortState {
+                column: column.to_string(),
+                direction: SortDirection::Descending,
+            }],
+            order: vec![column.to_string()],
+            hidden: vec![],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_memory_storage() {
+        let storage = MemoryStorage::new();
+        let shared = storage.clone();
+
+        storage.save("a", &state("name"));
+        storage.save("b", &state("age"));
+        storage.save("a", &state("title"));
+
+        assert_eq!(shared.load("a"), Some(state("title")));
+        assert_eq!(shared.load("b"), Some(state("age")));
+        assert_eq!(shared.load("c"), None);
+    }
+
+    #[cfg(feature = "file-storage")]
+    #[test]
+    fn test_json_file_storage() {
+        let dir = std::env::temp_dir().join(format!("dioxus-tabular-test-{}", std::process::id()));
+        let storage = JsonFileStorage::new(&dir);
+
+        assert_eq!(storage.load("users"), None);
+        storage.save("users", &state("name"));
+        assert_eq!(storage.load("users"), Some(state("name")));
+        assert_eq!(
+            storage.path("../users list"),
+            dir.join("___users_list.json")
+        );
+
+        // A corrupted file loads as no layout
+        std::fs::write(storage.path("users"), "not json").unwrap();
+        assert_eq!(storage.load("users"), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+use std::ops::Range;
+
+/// Computes which rows of a virtualized table are inside the viewport.
+///
+/// Rows have a fixed height in pixels. Only the rows in [`range`](Self::range) need to be
+/// rendered, with spacers of [`offset`](Self::offset) height standing in for the rest.
+/// Used by [`VirtualTableBody`](crate::VirtualTableBody).
+///
+/// # Example
+///
+/// ```
+/// use dioxus_tabular::VirtualWindow;
+///
+/// let window = VirtualWindow::new(20.0).with_overscan(2);
+///
+/// // 1000 rows, scrolled down by 400px in a 100px tall viewport
+/// let range = window.range(400.0, 100.0, 1000);
+/// assert_eq!(range, 18..27);
+/// assert_eq!(window.offset(range.start), 360.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VirtualWindow {
+    row_height: f64,
+    overscan: usize,
+}
+
+impl VirtualWindow {
+    /// Creates a new VirtualWindow for rows of the given height in pixels.
+    /// Non-positive heights are saturated to 1px.
+    pub fn new(row_height: f64) -> Self {
+        Self {
+            row_height: if row_height > 0.0 { row_height } else { 1.0 },
+            overscan: 0,
+        }
+    }
+
+    /// Sets the number of extra rows rendered above and below the viewport.
+    pub fn with_overscan(self, overscan: usize) -> Self {
+        Self { overscan, ..self }
+    }
+
+    /// Returns the height of a row in pixels.
+    pub fn row_height(&self) -> f64 {
+        self.row_height
+    }
+
+    /// Returns the range of row positions to render for the given scroll position and viewport height.
+    /// The range is saturated to `0..row_count`.
+    pub fn range(&self, scroll_top: f64, viewport_height: f64, row_count: usize) -> Range<usize> {
+        let scroll_top = scroll_top.max(0.0);
+        let first = (scroll_top / self.row_height).floor() as usize;
+        let last = ((scroll_top + viewport_height.max(0.0)) / self.row_height).ceil() as usize;
+
+        let end = last.saturating_add(self.overscan).min(row_count);
+        let start = first.saturating_sub(self.overscan).min(end);
+        start..end
+    }
+
+    /// Returns the vertical offset of the row at `index` in pixels.
+    pub fn offset(&self, index: usize) -> f64 {
+        index as f64 * self.row_height
+    }
+
+    /// Returns the total height of `row_count` rows in pixels.
+    pub fn total_height(&self, row_count: usize) -> f64 {
+        self.offset(row_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_at_top() {
+        let window = VirtualWindow::new(20.0);
+        assert_eq!(window.range(0.0, 100.0, 1000), 0..5);
+    }
+
+    #[test]
+    fn test_range_partial_rows() {
+        let window = VirtualWindow::new(20.0);
+        // Rows 2 and 7 are only partially visible
+        assert_eq!(window.range(50.0, 100.0, 1000), 2..8);
+    }
+
+    #[test]
+    fn test_range_with_overscan() {
+        let window = VirtualWindow::new(20.0).with_overscan(3);
+        assert_eq!(window.range(0.0, 100.0, 1000), 0..8);
+        assert_eq!(window.range(400.0, 100.0, 1000), 17..28);
+    }
+
+    #[test]
+    fn test_range_saturates_to_row_count() {
+        let window = VirtualWindow::new(20.0).with_overscan(3);
+        assert_eq!(window.range(1900.0, 200.0, 100), 92..100);
+        // Scrolled past the end (e.g. rows were filtered out)
+        assert_eq!(window.range(5000.0, 200.0, 100), 100..100);
+        assert_eq!(window.range(0.0, 100.0, 0), 0..0);
+    }
+
+    #[test]
+    fn test_negative_scroll_top() {
+        let window = VirtualWindow::new(20.0);
+        // Overscroll bounce on some platforms reports negative values
+        assert_eq!(window.range(-30.0, 100.0, 1000), 0..5);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let window = VirtualWindow::new(25.0);
+        assert_eq!(window.offset(4), 100.0);
+        assert_eq!(window.total_height(10), 250.0);
+    }
+
+    #[test]
+    fn test_non_positive_row_height() {
+        assert_eq!(VirtualWindow::new(0.0).row_height(), 1.0);
+        assert_eq!(VirtualWindow::new(-5.0).row_height(), 1.0);
+    }
+}